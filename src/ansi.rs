@@ -0,0 +1,133 @@
+use unicode_width::UnicodeWidthStr;
+
+const ESC: u8 = 0x1b;
+const BEL: u8 = 0x07;
+
+/// Returns the display width of `s` in columns, ignoring ANSI escape sequences
+///
+/// CSI sequences (such as SGR colors), OSC sequences (such as OSC 8 hyperlinks),
+/// DCS/SOS/PM/APC strings and other two character escape sequences do not
+/// count towards the width.
+///
+/// ## Example
+///
+/// ```
+/// use nls_term_grid::ansi_width;
+///
+/// assert_eq!(ansi_width("\x1b[1;34mdir\x1b[0m"), 3);
+/// ```
+pub fn ansi_width(s: &str) -> usize {
+    Segments::new(s)
+        .map(|segment| match segment {
+            Segment::Text(text) => UnicodeWidthStr::width(text),
+            Segment::Escape(_) => 0,
+        })
+        .sum()
+}
+
+/// Returns the display width of `s` before the first `anchor` outside of escape sequences
+///
+/// Returns the width of `s` if it does not contain `anchor`.
+pub(crate) fn anchor_offset(s: &str, anchor: char) -> usize {
+    let mut offset: usize = 0;
+
+    for segment in Segments::new(s) {
+        if let Segment::Text(text) = segment {
+            match text.find(anchor) {
+                Some(index) => return offset + UnicodeWidthStr::width(&text[..index]),
+                None => offset += UnicodeWidthStr::width(text),
+            }
+        }
+    }
+
+    offset
+}
+
+/// A part of a string that is either printable text or an escape sequence
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub(crate) enum Segment<'a> {
+    Text(&'a str),
+    Escape(&'a str),
+}
+
+/// Iterator splitting a string into text and escape sequence segments
+#[derive(Debug, Clone)]
+pub(crate) struct Segments<'a> {
+    remaining: &'a str,
+}
+
+impl<'a> Segments<'a> {
+    pub(crate) fn new(s: &'a str) -> Self {
+        Self { remaining: s }
+    }
+}
+
+impl<'a> Iterator for Segments<'a> {
+    type Item = Segment<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining.is_empty() {
+            return None;
+        }
+
+        let bytes = self.remaining.as_bytes();
+        let (segment, len) = if bytes[0] == ESC {
+            let len = escape_sequence_len(bytes);
+            (Segment::Escape(&self.remaining[..len]), len)
+        } else {
+            let len = bytes
+                .iter()
+                .position(|byte| *byte == ESC)
+                .unwrap_or(bytes.len());
+            (Segment::Text(&self.remaining[..len]), len)
+        };
+        self.remaining = &self.remaining[len..];
+
+        Some(segment)
+    }
+}
+
+/// Returns the length in bytes of the escape sequence at the start of `bytes`
+///
+/// `bytes[0]` must be ESC. Every byte used as a terminator is ASCII, so the
+/// returned length always falls on a char boundary.
+fn escape_sequence_len(bytes: &[u8]) -> usize {
+    match bytes.get(1) {
+        // CSI: parameter and intermediate bytes followed by a final byte in 0x40..=0x7e
+        Some(b'[') => bytes[2..]
+            .iter()
+            .position(|byte| (0x40..=0x7e).contains(byte))
+            .map_or(bytes.len(), |index| index + 3),
+        // OSC: terminated by BEL or ST
+        Some(b']') => string_terminator_len(bytes, true),
+        // DCS, SOS, PM and APC: terminated by ST
+        Some(b'P' | b'X' | b'^' | b'_') => string_terminator_len(bytes, false),
+        // other escape sequences: intermediate bytes followed by a final byte in 0x30..=0x7e
+        Some(_) => bytes[1..]
+            .iter()
+            .position(|byte| !(0x20..=0x2f).contains(byte))
+            .map_or(bytes.len(), |index| {
+                if (0x30..=0x7e).contains(&bytes[index + 1]) {
+                    index + 2
+                } else {
+                    // malformed sequence, only skip the ESC
+                    1
+                }
+            }),
+        None => 1,
+    }
+}
+
+fn string_terminator_len(bytes: &[u8], allow_bel: bool) -> usize {
+    let mut index = 2;
+
+    while index < bytes.len() {
+        match bytes[index] {
+            BEL if allow_bel => return index + 1,
+            ESC if bytes.get(index + 1) == Some(&b'\\') => return index + 2,
+            _ => index += 1,
+        }
+    }
+
+    bytes.len()
+}
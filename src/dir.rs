@@ -0,0 +1,259 @@
+use std::fs::{self, DirEntry, Metadata};
+use std::io;
+use std::path::Path;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::GridCell;
+
+/// Indicates which file types are written with an indicator after their name
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum IndicatorStyle {
+    /// No indicators are written
+    None,
+    /// `/` is written after directories, like `ls -p`
+    Slash,
+    /// `/` is written after directories, `*` after executables, `@` after symbolic links,
+    /// `|` after named pipes and `=` after sockets, like `ls -F`
+    Classify,
+}
+
+impl Default for IndicatorStyle {
+    #[inline]
+    fn default() -> Self {
+        Self::None
+    }
+}
+
+/// Indicates which files whose names start with `.` are listed
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum HiddenFiles {
+    /// Hidden files are not listed
+    Hide,
+    /// Hidden files are listed, except `.` and `..`, like `ls -A`
+    AlmostAll,
+    /// Hidden files are listed, including `.` and `..`, like `ls -a`
+    All,
+}
+
+impl Default for HiddenFiles {
+    #[inline]
+    fn default() -> Self {
+        Self::Hide
+    }
+}
+
+/// Options for building GridCells from the files of a directory, similar to the options of `ls`
+///
+/// GridCells are sorted by name, comparing the bytes of the names.
+/// Names which are not valid UTF-8 are converted lossily.
+///
+/// ## Example
+///
+/// A basic `ls -CF`:
+///
+/// ```no_run
+/// use nls_term_grid::{terminal_width, DirOptions, Direction, Grid, IndicatorStyle};
+///
+/// fn main() -> std::io::Result<()> {
+///     let cells = DirOptions::new()
+///         .with_indicator_style(IndicatorStyle::Classify)
+///         .read_dir(".")?;
+///     let grid = Grid::new("  ", Direction::TopToBottom, &cells);
+///
+///     match grid.fit_into_width(terminal_width()) {
+///         Some(display) => print!("{}", display),
+///         None => cells.iter().for_each(|cell| println!("{}", cell.contents)),
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct DirOptions {
+    indicator_style: IndicatorStyle,
+    hidden_files: HiddenFiles,
+    directories_first: bool,
+}
+
+/// A file to be listed
+struct Entry {
+    name: String,
+    /// The metadata of the file itself, which is `None` if it cannot be read
+    metadata: Option<Metadata>,
+    /// Whether the file is a directory or a symbolic link to a directory
+    is_directory: bool,
+}
+
+impl DirOptions {
+    /// Create new DirOptions without indicators, hiding hidden files
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets which file types are written with an indicator after their name
+    pub fn with_indicator_style(mut self, indicator_style: IndicatorStyle) -> Self {
+        self.indicator_style = indicator_style;
+        self
+    }
+
+    /// Sets which files whose names start with `.` are listed
+    pub fn with_hidden_files(mut self, hidden_files: HiddenFiles) -> Self {
+        self.hidden_files = hidden_files;
+        self
+    }
+
+    /// Sets whether directories are listed before other files,
+    /// like `ls --group-directories-first`
+    pub fn with_directories_first(mut self, directories_first: bool) -> Self {
+        self.directories_first = directories_first;
+        self
+    }
+
+    /// Returns a GridCell for every file of the directory at `path`
+    pub fn read_dir<P: AsRef<Path>>(&self, path: P) -> io::Result<Vec<GridCell<String>>> {
+        let path = path.as_ref();
+        let mut entries = Vec::new();
+
+        if self.hidden_files == HiddenFiles::All {
+            for name in [".", ".."] {
+                entries.push(Entry::new(String::from(name), &path.join(name)));
+            }
+        }
+        for dir_entry in fs::read_dir(path)? {
+            if let Some(entry) = self.entry_from_dir_entry(&dir_entry?) {
+                entries.push(entry);
+            }
+        }
+
+        Ok(self.cells(entries))
+    }
+
+    /// Returns a GridCell for every file of `dir_entries`, named with their file names
+    pub fn cells_from_entries<I>(&self, dir_entries: I) -> Vec<GridCell<String>>
+    where
+        I: IntoIterator<Item = DirEntry>,
+    {
+        let entries = dir_entries
+            .into_iter()
+            .filter_map(|dir_entry| self.entry_from_dir_entry(&dir_entry))
+            .collect();
+
+        self.cells(entries)
+    }
+
+    /// Returns a GridCell for every file of `paths`, named with their paths
+    ///
+    /// Hidden files are always listed, like the arguments of `ls`.
+    pub fn cells_from_paths<I, P>(&self, paths: I) -> Vec<GridCell<String>>
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<Path>,
+    {
+        let entries = paths
+            .into_iter()
+            .map(|path| {
+                let path = path.as_ref();
+                Entry::new(path.to_string_lossy().into_owned(), path)
+            })
+            .collect();
+
+        self.cells(entries)
+    }
+
+    /// Returns the entry of `dir_entry`, or `None` if it is hidden
+    fn entry_from_dir_entry(&self, dir_entry: &DirEntry) -> Option<Entry> {
+        let name = dir_entry.file_name().to_string_lossy().into_owned();
+        if name.starts_with('.') && self.hidden_files == HiddenFiles::Hide {
+            return None;
+        }
+
+        Some(Entry::new(name, &dir_entry.path()))
+    }
+
+    /// Sorts `entries` and returns their GridCells
+    fn cells(&self, mut entries: Vec<Entry>) -> Vec<GridCell<String>> {
+        entries.sort_by(|a, b| a.name.cmp(&b.name));
+        if self.directories_first {
+            // the sort is stable, so entries stay sorted by name within each group
+            entries.sort_by_key(|entry| !entry.is_directory);
+        }
+
+        entries
+            .into_iter()
+            .map(|entry| {
+                let indicator = entry.indicator(self.indicator_style);
+                let mut name = entry.name;
+                if let Some(indicator) = indicator {
+                    name.push(indicator);
+                }
+
+                GridCell::from(name)
+            })
+            .collect()
+    }
+}
+
+impl Entry {
+    fn new(name: String, path: &Path) -> Self {
+        let metadata = fs::symlink_metadata(path).ok();
+        let is_directory = match &metadata {
+            Some(metadata) if metadata.file_type().is_symlink() => path.is_dir(),
+            Some(metadata) => metadata.is_dir(),
+            None => false,
+        };
+
+        Self {
+            name,
+            metadata,
+            is_directory,
+        }
+    }
+
+    /// Returns the indicator written after the name of the file
+    fn indicator(&self, indicator_style: IndicatorStyle) -> Option<char> {
+        let metadata = self.metadata.as_ref()?;
+        let file_type = metadata.file_type();
+
+        match indicator_style {
+            IndicatorStyle::None => None,
+            IndicatorStyle::Slash => file_type.is_dir().then_some('/'),
+            IndicatorStyle::Classify if file_type.is_dir() => Some('/'),
+            IndicatorStyle::Classify if file_type.is_symlink() => Some('@'),
+            IndicatorStyle::Classify if file_type.is_file() => {
+                is_executable(metadata).then_some('*')
+            }
+            IndicatorStyle::Classify => special_file_indicator(&file_type),
+        }
+    }
+}
+
+#[cfg(unix)]
+fn is_executable(metadata: &Metadata) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+
+    metadata.permissions().mode() & 0o111 != 0
+}
+
+#[cfg(not(unix))]
+fn is_executable(_metadata: &Metadata) -> bool {
+    false
+}
+
+#[cfg(unix)]
+fn special_file_indicator(file_type: &fs::FileType) -> Option<char> {
+    use std::os::unix::fs::FileTypeExt;
+
+    if file_type.is_fifo() {
+        Some('|')
+    } else if file_type.is_socket() {
+        Some('=')
+    } else {
+        None
+    }
+}
+
+#[cfg(not(unix))]
+fn special_file_indicator(_file_type: &fs::FileType) -> Option<char> {
+    None
+}
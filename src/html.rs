@@ -0,0 +1,158 @@
+use alloc::string::ToString;
+use core::fmt::{self, Write};
+
+use crate::ansi::{ansi_width, Segment, Segments};
+use crate::{write_padding, Alignment, Display, PositionedCell, VerticalAlignment};
+
+/// An HTML `<table>` containing the rows and columns of a [`Display`](struct.Display.html)
+///
+/// Every row of the grid is written as a `<tr>` and every GridCell as a `<td>`,
+/// in the same arrangement as the grid is written to a terminal. ANSI escape
+/// sequences are removed from contents, and contents and hyperlinks are escaped.
+/// The table is written with `white-space: pre`, so that GridCells with
+/// more than one line and anchored GridCells are kept as they are.
+///
+/// ## Example
+///
+/// ```
+/// use nls_term_grid::{Alignment, Direction, Grid, GridCell};
+///
+/// let mut size = GridCell::from(String::from("100"));
+/// size.alignment = Alignment::Right;
+/// let cells = [
+///     GridCell::from(String::from("\x1b[1;34m<dir>\x1b[0m")),
+///     size,
+/// ];
+/// let grid = Grid::new("  ", Direction::LeftToRight, &cells);
+///
+/// assert_eq!(
+///     grid.fit_into_columns(2).html().to_string(),
+///     "<table style=\"white-space: pre\">\n\
+///      <tr><td>&lt;dir&gt;</td><td style=\"text-align: right\">100</td></tr>\n\
+///      </table>\n"
+/// );
+/// ```
+#[derive(Debug)]
+pub struct Html<'display, 'grid, D: fmt::Display> {
+    display: &'display Display<'grid, D>,
+}
+
+impl<'grid, D: fmt::Display> Display<'grid, D> {
+    /// Returns the grid as an HTML `<table>`, see [`Html`](struct.Html.html)
+    pub fn html(&self) -> Html<'_, 'grid, D> {
+        Html { display: self }
+    }
+}
+
+impl<D: fmt::Display> Html<'_, '_, D> {
+    /// Writes the `<td>` of the GridCell at `positioned_cell`
+    fn write_cell(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        positioned_cell: &PositionedCell,
+    ) -> fmt::Result {
+        let display = self.display;
+        let cell = &display.grid.cells[positioned_cell.index];
+        let contents = if cell.total_width() > display.width_limit {
+            cell.truncated(display.width_limit, display.grid.options.truncation())
+                .contents
+        } else {
+            cell.contents.to_string()
+        };
+
+        let alignment = display.grid.cell_alignment(cell, positioned_cell.column);
+        match alignment {
+            Alignment::Left | Alignment::Anchored(_) => f.write_str("<td>")?,
+            Alignment::Right => f.write_str("<td style=\"text-align: right\">")?,
+            Alignment::Center => f.write_str("<td style=\"text-align: center\">")?,
+        }
+        if let Some(hyperlink) = &cell.hyperlink {
+            f.write_str("<a href=\"")?;
+            write_escaped(f, hyperlink)?;
+            f.write_str("\">")?;
+        }
+
+        for (line_index, line) in contents.split('\n').enumerate() {
+            if line_index != 0 {
+                f.write_char('\n')?;
+            }
+            // anchored GridCells are lined up with spaces, the same as in a terminal
+            if let Alignment::Anchored(_) = alignment {
+                let (left_pad_width, _) = alignment.pad_widths(
+                    ansi_width(line) + cell.icon_width(),
+                    positioned_cell.width,
+                    display.dimentions.anchor_widths[positioned_cell.column],
+                );
+                write_padding(f, left_pad_width)?;
+            }
+            match &cell.icon {
+                Some(icon) if line_index == 0 => {
+                    write_escaped(f, &icon.glyph)?;
+                    write_padding(f, icon.gap)?;
+                }
+                Some(icon) => write_padding(f, icon.total_width())?,
+                None => (),
+            }
+            for segment in Segments::new(line) {
+                if let Segment::Text(text) = segment {
+                    write_escaped(f, text)?;
+                }
+            }
+        }
+
+        if cell.hyperlink.is_some() {
+            f.write_str("</a>")?;
+        }
+        f.write_str("</td>")
+    }
+}
+
+impl<D: fmt::Display> fmt::Display for Html<'_, '_, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let display = self.display;
+        let vertical_align = match display.grid.options.vertical_alignment {
+            VerticalAlignment::Top => "top",
+            VerticalAlignment::Middle => "middle",
+            VerticalAlignment::Bottom => "bottom",
+        };
+
+        writeln!(f, "<table style=\"white-space: pre\">")?;
+        for row in 0..display.num_rows() {
+            let is_multi_line = display
+                .row_positioned_cells(row)
+                .any(|positioned_cell| display.grid.cells[positioned_cell.index].num_lines() > 1);
+            if is_multi_line {
+                write!(f, "<tr style=\"vertical-align: {}\">", vertical_align)?;
+            } else {
+                f.write_str("<tr>")?;
+            }
+
+            for positioned_cell in display.row_positioned_cells(row) {
+                self.write_cell(f, &positioned_cell)?;
+            }
+            writeln!(f, "</tr>")?;
+        }
+        writeln!(f, "</table>")
+    }
+}
+
+/// Writes `s` with the characters special to HTML replaced by character references
+fn write_escaped<F: fmt::Write>(f: &mut F, s: &str) -> fmt::Result {
+    let mut start: usize = 0;
+
+    for (index, c) in s.char_indices() {
+        let reference = match c {
+            '&' => "&amp;",
+            '<' => "&lt;",
+            '>' => "&gt;",
+            '"' => "&quot;",
+            '\'' => "&#39;",
+            _ => continue,
+        };
+        f.write_str(&s[start..index])?;
+        f.write_str(reference)?;
+        start = index + c.len_utf8();
+    }
+
+    f.write_str(&s[start..])
+}
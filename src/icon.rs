@@ -0,0 +1,68 @@
+use alloc::borrow::Cow;
+
+use unicode_width::UnicodeWidthStr;
+
+/// An icon written before the contents of a GridCell, such as a Nerd Font or emoji glyph
+///
+/// The width of many icon glyphs depends on the font and the terminal, so the
+/// width of an icon can be set to the number of columns the terminal uses for
+/// it. Columns are calculated with the width of the icon and the gap after it,
+/// so icons line up in every column as long as the width is correct.
+///
+/// ## Example
+///
+/// ```
+/// use nls_term_grid::{Direction, Grid, GridCell, Icon};
+///
+/// let cells = [
+///     GridCell::from(String::from("src")).with_icon(Icon::new("\u{f115}").with_width(2)),
+///     GridCell::from(String::from("Cargo.toml")).with_icon(Icon::new("\u{e7a8}").with_width(2)),
+/// ];
+/// let grid = Grid::new("  ", Direction::LeftToRight, &cells);
+/// let display = grid.fit_into_columns(2);
+///
+/// assert_eq!(cells[0].total_width(), 6);
+/// assert_eq!(display.column_widths(), &[6, 13]);
+/// assert_eq!(display.to_string(), "\u{f115} src  \u{e7a8} Cargo.toml\n");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Icon {
+    /// The glyph of the icon
+    pub glyph: Cow<'static, str>,
+    /// The number of columns the glyph is written in
+    pub width: usize,
+    /// The number of spaces between the icon and contents
+    pub gap: usize,
+}
+
+impl Icon {
+    /// Create a new Icon with the display width of `glyph` and a gap of 1 space
+    pub fn new<S: Into<Cow<'static, str>>>(glyph: S) -> Self {
+        let glyph = glyph.into();
+
+        Self {
+            width: UnicodeWidthStr::width(&*glyph),
+            glyph,
+            gap: 1,
+        }
+    }
+
+    /// Sets the number of columns the glyph is written in
+    pub fn with_width(mut self, width: usize) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the number of spaces between the icon and contents
+    pub fn with_gap(mut self, gap: usize) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Returns the width of the icon and the gap after it
+    #[inline]
+    pub(crate) fn total_width(&self) -> usize {
+        self.width.saturating_add(self.gap)
+    }
+}
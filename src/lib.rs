@@ -2,20 +2,58 @@
 #![doc = include_str!("../README.md")]
 
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 use alloc::borrow::Cow;
 use alloc::string::String;
 use alloc::vec;
 use alloc::vec::Vec;
-use core::fmt;
+use core::fmt::{self, Write};
+use core::ops::Range;
 
 use unicode_width::UnicodeWidthStr;
 
+use search::WidthSearch;
+
+mod ansi;
+#[cfg(feature = "std")]
+mod dir;
+mod html;
+mod icon;
+mod long;
+#[cfg(feature = "ls_colors")]
+mod ls_colors;
+mod owned;
+mod search;
+mod streaming;
+mod style;
+mod table;
+#[cfg(feature = "std")]
+mod terminal;
 #[cfg(test)]
 mod tests;
+mod truncate;
+
+pub use ansi::ansi_width;
+#[cfg(feature = "std")]
+pub use dir::{DirOptions, HiddenFiles, IndicatorStyle};
+pub use html::Html;
+pub use icon::Icon;
+pub use long::LongListing;
+#[cfg(feature = "ls_colors")]
+pub use ls_colors::{FileType, LsColors};
+pub use owned::OwnedGrid;
+pub use streaming::StreamingGrid;
+pub use style::{colors_enabled, set_colors_enabled, Color, Painted, Style};
+pub use table::{BorderStyle, Table};
+#[cfg(feature = "std")]
+pub use terminal::{should_color_stdout, stdout_is_tty, terminal_width};
+pub use truncate::{Overflow, Truncation, TruncationPosition};
 
 /// Indicates alignment of contents when padding is required
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Alignment {
     /// Padding is added to right side of text
     ///
@@ -37,6 +75,32 @@ pub enum Alignment {
     /// file12
     /// ```
     Right,
+    /// Padding is split between both sides of text, with the extra
+    /// space going to the right side when padding is odd
+    ///
+    /// ## Example
+    ///
+    /// ```text
+    ///  file
+    /// file1
+    /// file12
+    /// ```
+    Center,
+    /// Padding is added so that the anchor of contents lines up with the
+    /// anchors of other anchored contents in the same column
+    ///
+    /// The value is the display width of contents before the anchor, see
+    /// [`GridCell::with_anchor`](struct.GridCell.html#method.with_anchor).
+    /// Anchored contents are placed as far right as the column allows.
+    ///
+    /// ## Example
+    ///
+    /// ```text
+    ///   1.5K
+    ///  12.25M
+    /// 100
+    /// ```
+    Anchored(usize),
 }
 
 impl Default for Alignment {
@@ -46,45 +110,278 @@ impl Default for Alignment {
     }
 }
 
+impl Alignment {
+    /// Returns the padding needed on the (left, right) side of contents
+    /// of `contents_width` to fill `width`
+    ///
+    /// `anchor_width` is the width from the anchor of the column to its right edge
+    pub(crate) fn pad_widths(
+        self,
+        contents_width: usize,
+        width: usize,
+        anchor_width: usize,
+    ) -> (usize, usize) {
+        let pad_width: usize = width.saturating_sub(contents_width);
+
+        match self {
+            Self::Left => (0, pad_width),
+            Self::Right => (pad_width, 0),
+            Self::Center => (pad_width / 2, pad_width - pad_width / 2),
+            Self::Anchored(offset) => {
+                let left_pad_width = width
+                    .saturating_sub(anchor_width)
+                    .saturating_sub(offset)
+                    .min(pad_width);
+
+                (left_pad_width, pad_width - left_pad_width)
+            }
+        }
+    }
+}
+
+/// Indicates vertical alignment of GridCells in a row containing GridCells
+/// with more than one line
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum VerticalAlignment {
+    /// Empty lines are added below contents
+    Top,
+    /// Empty lines are split between above and below contents, with the
+    /// extra line going below contents when the number of empty lines is odd
+    Middle,
+    /// Empty lines are added above contents
+    Bottom,
+}
+
+impl Default for VerticalAlignment {
+    #[inline]
+    fn default() -> Self {
+        Self::Top
+    }
+}
+
+impl VerticalAlignment {
+    /// Returns the number of empty lines above contents of `num_lines`
+    /// lines to fill `row_height` lines
+    pub(crate) fn top_pad_height(self, num_lines: usize, row_height: usize) -> usize {
+        let pad_height = row_height.saturating_sub(num_lines);
+
+        match self {
+            Self::Top => 0,
+            Self::Middle => pad_height / 2,
+            Self::Bottom => pad_height,
+        }
+    }
+}
+
 /// A textual string containing its display width and alignment
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GridCell<D: fmt::Display> {
     /// The textual string displayed when written
     pub contents: D,
-    /// The display width of contents in columns
+    /// The display width of contents in columns, which is the width of
+    /// the widest line when contents contains more than one line
+    ///
+    /// The width does not include the icon, see [`total_width`](#method.total_width).
     pub width: usize,
+    /// The number of lines of contents, where lines are seperated by `'\n'`
+    ///
+    /// A height of 0 is treated the same as a height of 1.
+    pub height: usize,
     /// Whether contents is (left/right) aligned when padding is required
     pub alignment: Alignment,
+    /// The target of an OSC 8 hyperlink written around contents, e.g. a `file://` URI
+    ///
+    /// The hyperlink does not count towards the width and padding is written
+    /// outside of the hyperlink, so only the contents are clickable.
+    pub hyperlink: Option<String>,
+    /// The style contents are written with
+    ///
+    /// The style does not count towards the width and padding is written
+    /// outside of the style. Styles are not written while colors are
+    /// disabled with [`set_colors_enabled`](fn.set_colors_enabled.html).
+    pub style: Option<Style>,
+    /// The icon written before contents
+    ///
+    /// Lines after the first line of contents are indented by the width of the icon.
+    pub icon: Option<Icon>,
 }
 
 impl<D: fmt::Display> GridCell<D> {
-    pub(crate) fn write<F: fmt::Write>(&self, f: &mut F, width: usize) -> fmt::Result {
-        let pad_width: usize = if width <= self.width {
-            0
-        } else {
-            width - self.width
-        };
+    /// Sets the target of the OSC 8 hyperlink written around contents
+    pub fn with_hyperlink<S: Into<String>>(mut self, hyperlink: S) -> Self {
+        self.hyperlink = Some(hyperlink.into());
+        self
+    }
+
+    /// Sets the style contents are written with
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    /// Sets the icon written before contents
+    pub fn with_icon(mut self, icon: Icon) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Returns the number of columns the GridCell is written in,
+    /// which is the width of contents and the width of the icon and its gap
+    #[inline]
+    pub fn total_width(&self) -> usize {
+        self.width.saturating_add(self.icon_width())
+    }
+
+    /// Returns the width of the icon and its gap, or 0 without an icon
+    #[inline]
+    pub(crate) fn icon_width(&self) -> usize {
+        self.icon.as_ref().map_or(0, Icon::total_width)
+    }
 
+    /// Writes contents with the given padding on the left and right side
+    pub(crate) fn write<F: fmt::Write>(
+        &self,
+        f: &mut F,
+        left_pad_width: usize,
+        right_pad_width: usize,
+    ) -> fmt::Result {
         // Check if pad width is 0
-        if pad_width == 0 {
+        if left_pad_width == 0 && right_pad_width == 0 {
             // if pad width is 0, we do not need to do padding
-            write!(f, "{}", self.contents)
-        } else if self.alignment == Alignment::Left {
-            write!(f, "{}{}", self.contents, " ".repeat(pad_width))
+            self.write_contents(f)
         } else {
-            write!(f, "{}{}", " ".repeat(pad_width), self.contents)
+            write_padding(f, left_pad_width)?;
+            self.write_contents(f)?;
+            write_padding(f, right_pad_width)
+        }
+    }
+
+    /// Writes contents without padding, wrapped in its style and hyperlink if there are any
+    pub(crate) fn write_contents<F: fmt::Write>(&self, f: &mut F) -> fmt::Result {
+        self.write_icon(f, true)?;
+        write_decorated(f, self.style, self.hyperlink.as_deref(), &self.contents)
+    }
+
+    /// Writes the icon and its gap before the first line of contents,
+    /// or the same width of spaces before the other lines
+    pub(crate) fn write_icon<F: fmt::Write>(&self, f: &mut F, is_first_line: bool) -> fmt::Result {
+        match &self.icon {
+            Some(icon) if is_first_line => {
+                write_decorated(f, self.style, None, &*icon.glyph)?;
+                write_padding(f, icon.gap)
+            }
+            Some(icon) => write_padding(f, icon.total_width()),
+            None => Ok(()),
         }
     }
+
+    /// Returns the number of lines of contents
+    #[inline]
+    pub(crate) fn num_lines(&self) -> usize {
+        self.height.max(1)
+    }
+}
+
+/// Spaces padding is written from, so that padding does not need to be allocated
+static PADDING: &str = "                                                                ";
+
+/// Writes `width` spaces
+fn write_padding<F: fmt::Write>(f: &mut F, mut width: usize) -> fmt::Result {
+    while width > 0 {
+        let chunk_width = width.min(PADDING.len());
+        f.write_str(&PADDING[..chunk_width])?;
+        width -= chunk_width;
+    }
+    Ok(())
+}
+
+/// Writes `contents` with `style` if colors are enabled, wrapped in an
+/// OSC 8 hyperlink to `hyperlink` if there is one
+fn write_decorated<F, D>(
+    f: &mut F,
+    style: Option<Style>,
+    hyperlink: Option<&str>,
+    contents: D,
+) -> fmt::Result
+where
+    F: fmt::Write,
+    D: fmt::Display,
+{
+    if let Some(hyperlink) = hyperlink {
+        write!(f, "\x1b]8;;{}\x1b\\", hyperlink)?;
+    }
+    match style {
+        Some(style) if colors_enabled() => style.write_painted(f, contents)?,
+        _ => write!(f, "{}", contents)?,
+    }
+    if hyperlink.is_some() {
+        f.write_str("\x1b]8;;\x1b\\")?;
+    }
+    Ok(())
+}
+
+/// Returns the width of the widest line of `s` and the number of lines of `s`
+fn lines_width_and_height(s: &str, width: fn(&str) -> usize) -> (usize, usize) {
+    s.split('\n').fold((0, 0), |(max_width, height), line| {
+        (max_width.max(width(line)), height + 1)
+    })
 }
 
 impl From<String> for GridCell<String> {
     fn from(value: String) -> Self {
-        let width = UnicodeWidthStr::width(&*value);
+        let (width, height) = lines_width_and_height(&value, UnicodeWidthStr::width);
 
         Self {
             contents: value,
-            width: width,
+            width,
+            height,
             alignment: Alignment::Left,
+            hyperlink: None,
+            style: None,
+            icon: None,
+        }
+    }
+}
+
+impl GridCell<String> {
+    /// Aligns contents on the first occurrence of `anchor`, e.g. `'.'` for decimal points
+    ///
+    /// Contents without `anchor` are anchored at their end, so whole numbers
+    /// line up with the integer part of fractional numbers. ANSI escape
+    /// sequences are ignored when looking for `anchor`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use nls_term_grid::{Alignment, GridCell};
+    ///
+    /// let cell = GridCell::from(String::from("12.25M")).with_anchor('.');
+    ///
+    /// assert_eq!(cell.alignment, Alignment::Anchored(2));
+    /// ```
+    pub fn with_anchor(mut self, anchor: char) -> Self {
+        self.alignment = Alignment::Anchored(ansi::anchor_offset(&self.contents, anchor));
+        self
+    }
+
+    /// Create a GridCell whose width ignores ANSI escape sequences in `value`
+    ///
+    /// Use this instead of `GridCell::from` when contents are colored
+    /// with SGR escape sequences, so that the escape sequences do not count
+    /// towards the width of the GridCell.
+    pub fn from_ansi_string(value: String) -> Self {
+        let (width, height) = lines_width_and_height(&value, ansi_width);
+
+        Self {
+            contents: value,
+            width,
+            height,
+            alignment: Alignment::Left,
+            hyperlink: None,
+            style: None,
+            icon: None,
         }
     }
 }
@@ -93,9 +390,81 @@ impl From<String> for GridCell<String> {
 #[derive(Debug, Default)]
 pub struct Grid<'cells, 'seperator, D: fmt::Display> {
     cells: &'cells [GridCell<D>],
-    seperator: Cow<'seperator, str>,
-    seperator_width: usize,
-    direction: Direction,
+    options: GridOptions<'seperator>,
+}
+
+/// The options of a grid, shared by [`Grid`](struct.Grid.html) and
+/// [`OwnedGrid`](struct.OwnedGrid.html)
+#[derive(Debug, Default, Clone)]
+pub(crate) struct GridOptions<'seperator> {
+    pub(crate) seperator: Cow<'seperator, str>,
+    pub(crate) seperator_width: usize,
+    pub(crate) direction: Direction,
+    pub(crate) column_alignments: Vec<Option<Alignment>>,
+    pub(crate) layout_strategy: LayoutStrategy,
+    pub(crate) overflow: Overflow,
+    pub(crate) vertical_alignment: VerticalAlignment,
+    pub(crate) max_columns: Option<usize>,
+    pub(crate) min_column_width: usize,
+    pub(crate) max_column_width: Option<usize>,
+}
+
+impl<'seperator> GridOptions<'seperator> {
+    pub(crate) fn new(seperator: Cow<'seperator, str>, direction: Direction) -> Self {
+        let seperator_width = UnicodeWidthStr::width(&*seperator);
+
+        Self {
+            seperator,
+            seperator_width,
+            direction,
+            column_alignments: Vec::new(),
+            layout_strategy: LayoutStrategy::WellPacked,
+            overflow: Overflow::Fail,
+            vertical_alignment: VerticalAlignment::Top,
+            max_columns: None,
+            min_column_width: 0,
+            max_column_width: None,
+        }
+    }
+
+    /// Returns the width GridCells wider than are truncated to by `fit_into_width`,
+    /// or `None` if no GridCell can fit within the display width
+    pub(crate) fn width_limit(&self, display_width: usize) -> Option<usize> {
+        let width_limit = match self.overflow {
+            Overflow::Fail => usize::MAX,
+            Overflow::Truncate(_) => display_width.checked_sub(1)?,
+        };
+
+        Some(width_limit.min(self.column_width_limit()))
+    }
+
+    /// Returns the width GridCells wider than are truncated to
+    /// when the number of columns or rows is chosen by the caller
+    #[inline]
+    pub(crate) fn column_width_limit(&self) -> usize {
+        self.max_column_width.unwrap_or(usize::MAX)
+    }
+
+    /// Returns the width a GridCell of `cell_width` takes up in its column
+    #[inline]
+    pub(crate) fn column_width(&self, cell_width: usize, width_limit: usize) -> usize {
+        cell_width.min(width_limit).max(self.min_column_width)
+    }
+
+    /// Returns the maximum number of columns chosen by `fit_into_width`
+    #[inline]
+    pub(crate) fn max_columns(&self) -> usize {
+        self.max_columns.unwrap_or(usize::MAX).max(1)
+    }
+
+    /// Returns how GridCells wider than the width limit are truncated
+    #[inline]
+    pub(crate) fn truncation(&self) -> &Truncation {
+        match &self.overflow {
+            Overflow::Fail => &truncate::DEFAULT_TRUNCATION,
+            Overflow::Truncate(truncation) => truncation,
+        }
+    }
 }
 
 impl<'cells, 'seperator, D: fmt::Display> Grid<'cells, 'seperator, D> {
@@ -104,60 +473,353 @@ impl<'cells, 'seperator, D: fmt::Display> Grid<'cells, 'seperator, D> {
     where
         S: Into<Cow<'seperator, str>>,
     {
-        let seperator: Cow<'_, str> = seperator.into();
-        let seperator_width = UnicodeWidthStr::width(&*seperator);
-
         Self {
             cells,
-            seperator,
-            seperator_width,
-            direction,
+            options: GridOptions::new(seperator.into(), direction),
         }
     }
 
-    #[inline]
-    pub(crate) fn total_cell_count(&self) -> usize {
-        self.cells.len()
+    /// Sets what happens to GridCells too wide to fit into the display width
+    /// given to [`fit_into_width`](#method.fit_into_width)
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use nls_term_grid::{Direction, Grid, GridCell, Overflow, Truncation};
+    ///
+    /// let cells = [
+    ///     GridCell::from(String::from("file1")),
+    ///     GridCell::from(String::from("a_very_long_file_name")),
+    /// ];
+    ///
+    /// let grid = Grid::new("  ", Direction::TopToBottom, &cells)
+    ///     .with_overflow(Overflow::Truncate(Truncation::default()));
+    /// let display = grid.fit_into_width(12).unwrap();
+    ///
+    /// assert_eq!(display.to_string(), "file1\na_very_lon…\n");
+    /// ```
+    pub fn with_overflow(mut self, overflow: Overflow) -> Self {
+        self.options.overflow = overflow;
+        self
+    }
+
+    /// Sets the vertical alignment of GridCells in rows containing
+    /// GridCells with more than one line
+    ///
+    /// The height of a row is the height of its tallest GridCell.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use nls_term_grid::{Direction, Grid, GridCell, VerticalAlignment};
+    ///
+    /// let cells = [
+    ///     GridCell::from(String::from("file1")),
+    ///     GridCell::from(String::from("file2\n4 KiB\n2024-01-01")),
+    /// ];
+    ///
+    /// let grid = Grid::new("  ", Direction::LeftToRight, &cells)
+    ///     .with_vertical_alignment(VerticalAlignment::Middle);
+    ///
+    /// assert_eq!(
+    ///     grid.fit_into_columns(2).to_string(),
+    ///     "       file2\n\
+    ///      file1  4 KiB\n\
+    ///      \x20      2024-01-01\n"
+    /// );
+    /// ```
+    pub fn with_vertical_alignment(mut self, vertical_alignment: VerticalAlignment) -> Self {
+        self.options.vertical_alignment = vertical_alignment;
+        self
+    }
+
+    /// Sets how the number of columns is chosen by [`fit_into_width`](#method.fit_into_width)
+    pub fn with_layout_strategy(mut self, layout_strategy: LayoutStrategy) -> Self {
+        self.options.layout_strategy = layout_strategy;
+        self
+    }
+
+    /// Sets the maximum number of columns chosen by [`fit_into_width`](#method.fit_into_width)
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use nls_term_grid::{Direction, Grid, GridCell};
+    ///
+    /// let cells: Vec<GridCell<String>> = (1..=6)
+    ///     .map(|i| GridCell::from(format!("file{}", i)))
+    ///     .collect();
+    ///
+    /// let grid = Grid::new("  ", Direction::LeftToRight, &cells).with_max_columns(2);
+    ///
+    /// assert_eq!(
+    ///     grid.fit_into_width(80).unwrap().to_string(),
+    ///     "file1  file2\nfile3  file4\nfile5  file6\n"
+    /// );
+    /// ```
+    pub fn with_max_columns(mut self, max_columns: usize) -> Self {
+        self.options.max_columns = Some(max_columns);
+        self
+    }
+
+    /// Sets the minimum width of every column containing a GridCell
+    pub fn with_min_column_width(mut self, min_column_width: usize) -> Self {
+        self.options.min_column_width = min_column_width;
+        self
+    }
+
+    /// Sets the maximum width of every column
+    ///
+    /// GridCells wider than the maximum column width are truncated, with the
+    /// [`Truncation`](struct.Truncation.html) of [`Overflow::Truncate`](enum.Overflow.html#variant.Truncate)
+    /// if it is set or the default `Truncation` otherwise.
+    pub fn with_max_column_width(mut self, max_column_width: usize) -> Self {
+        self.options.max_column_width = Some(max_column_width);
+        self
+    }
+
+    /// Overrides the alignment of GridCells in each column once the layout is known
+    ///
+    /// The n-th alignment applies to the n-th column. Columns with `None` or
+    /// without an alignment keep the alignment of each GridCell.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use nls_term_grid::{Alignment, Direction, Grid, GridCell};
+    ///
+    /// let cells = [
+    ///     GridCell::from(String::from("file1")),
+    ///     GridCell::from(String::from("1")),
+    ///     GridCell::from(String::from("file2")),
+    ///     GridCell::from(String::from("100")),
+    /// ];
+    ///
+    /// let grid = Grid::new(" ", Direction::LeftToRight, &cells)
+    ///     .with_column_alignments([None, Some(Alignment::Right)]);
+    ///
+    /// assert_eq!(grid.fit_into_columns(2).to_string(), "file1   1 \nfile2 100 \n");
+    /// ```
+    pub fn with_column_alignments<A>(mut self, column_alignments: A) -> Self
+    where
+        A: Into<Vec<Option<Alignment>>>,
+    {
+        self.options.column_alignments = column_alignments.into();
+        self
     }
 
     /// Returns a displayable containing the specified number of columns
     pub fn fit_into_columns(&self, num_columns: usize) -> Display<'_, D> {
-        let dimentions = self.calculate_dimentions(num_columns);
+        self.view().fit_into_columns(num_columns)
+    }
 
-        Display {
-            dimentions: dimentions,
-            grid: self,
-        }
+    /// Returns a displayable containing at most the specified number of rows
+    ///
+    /// The number of columns is the fewest columns needed to fit every GridCell
+    /// into `num_rows` rows. With [`Direction::TopToBottom`](enum.Direction.html#variant.TopToBottom),
+    /// there are exactly `num_rows` rows if there are at least `num_rows` GridCells.
+    /// With [`Direction::LeftToRight`](enum.Direction.html#variant.LeftToRight),
+    /// every row except the last is full, so there may be fewer rows.
+    /// A `num_rows` of 0 is treated the same as 1.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use nls_term_grid::{Direction, Grid, GridCell};
+    ///
+    /// let cells: Vec<GridCell<String>> = (1..=5)
+    ///     .map(|i| GridCell::from(format!("file{}", i)))
+    ///     .collect();
+    ///
+    /// let grid = Grid::new("  ", Direction::TopToBottom, &cells);
+    ///
+    /// assert_eq!(
+    ///     grid.fit_into_rows(4).to_string(),
+    ///     "file1  file5\nfile2\nfile3\nfile4\n"
+    /// );
+    /// ```
+    pub fn fit_into_rows(&self, num_rows: usize) -> Display<'_, D> {
+        self.view().fit_into_rows(num_rows)
+    }
+
+    /// Returns a displayable containing at most the specified number of rows,
+    /// see [`fit_into_rows`](#method.fit_into_rows)
+    ///
+    /// Returns `None` if the total width of the grid is greator than the display width.
+    pub fn fit_into_rows_within_width(
+        &self,
+        num_rows: usize,
+        display_width: usize,
+    ) -> Option<Display<'_, D>> {
+        self.view()
+            .fit_into_rows_within_width(num_rows, display_width)
     }
 
     /// Returns a well packed displayable grid fitted within display width
     ///
-    /// Returns `None` if one of the GridCell contains a width greator than the display width
+    /// Returns `None` if one of the GridCell contains a width greator than the display width,
+    /// unless the GridCells are truncated with [`Overflow::Truncate`](enum.Overflow.html#variant.Truncate)
     pub fn fit_into_width(&self, display_width: usize) -> Option<Display<'_, D>> {
+        self.view().fit_into_width(display_width)
+    }
+
+    /// Returns a displayable using a [`Layout`](struct.Layout.html) computed earlier,
+    /// without computing the layout again
+    ///
+    /// The GridCells and options of the grid should be the same as when the layout
+    /// was computed. GridCells outside of the layout are not written.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use nls_term_grid::{Direction, Grid, GridCell};
+    ///
+    /// let cells = [
+    ///     GridCell::from(String::from("file1")),
+    ///     GridCell::from(String::from("file10")),
+    ///     GridCell::from(String::from("file100")),
+    /// ];
+    /// let grid = Grid::new("  ", Direction::TopToBottom, &cells);
+    /// let layout = grid.fit_into_width(20).unwrap().layout();
+    ///
+    /// assert_eq!(
+    ///     grid.display_with_layout(layout).to_string(),
+    ///     "file1   file100\nfile10\n"
+    /// );
+    /// ```
+    pub fn display_with_layout(&self, layout: Layout) -> Display<'_, D> {
+        self.view().display_with_layout(layout)
+    }
+
+    #[inline]
+    pub(crate) fn view(&self) -> GridView<'_, D> {
+        GridView {
+            cells: self.cells,
+            options: &self.options,
+        }
+    }
+}
+
+/// A borrowed grid, which contains the layout shared by every grid type
+#[derive(Debug)]
+pub(crate) struct GridView<'grid, D: fmt::Display> {
+    pub(crate) cells: &'grid [GridCell<D>],
+    pub(crate) options: &'grid GridOptions<'grid>,
+}
+
+impl<D: fmt::Display> Clone for GridView<'_, D> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<D: fmt::Display> Copy for GridView<'_, D> {}
+
+impl<'grid, D: fmt::Display> GridView<'grid, D> {
+    /// Returns the alignment of `cell` when written in the column at `column_index`
+    #[inline]
+    pub(crate) fn cell_alignment(self, cell: &GridCell<D>, column_index: usize) -> Alignment {
+        let alignment = self
+            .options
+            .column_alignments
+            .get(column_index)
+            .copied()
+            .flatten()
+            .unwrap_or(cell.alignment);
+
+        // the anchor is after the icon
+        match alignment {
+            Alignment::Anchored(offset) => {
+                Alignment::Anchored(offset.saturating_add(cell.icon_width()))
+            }
+            alignment => alignment,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn total_cell_count(self) -> usize {
+        self.cells.len()
+    }
+
+    pub(crate) fn fit_into_columns(self, num_columns: usize) -> Display<'grid, D> {
+        self.display_with_columns(num_columns, self.options.column_width_limit())
+    }
+
+    pub(crate) fn fit_into_rows(self, num_rows: usize) -> Display<'grid, D> {
+        let num_rows = num_rows.max(1).min(self.total_cell_count());
+        let num_columns = if num_rows == 0 {
+            0
+        } else {
+            usize_div_ceil(self.total_cell_count(), num_rows)
+        };
+        // with left to right, every row except the last is full
+        let num_rows = match self.options.direction {
+            Direction::LeftToRight if num_columns != 0 => {
+                usize_div_ceil(self.total_cell_count(), num_columns)
+            }
+            _ => num_rows,
+        };
+
+        let width_limit = self.options.column_width_limit();
+
+        Display {
+            dimentions: self.calculate_dimentions_with_rows(num_rows, num_columns, width_limit),
+            grid: self,
+            width_limit,
+        }
+    }
+
+    pub(crate) fn fit_into_rows_within_width(
+        self,
+        num_rows: usize,
+        display_width: usize,
+    ) -> Option<Display<'grid, D>> {
+        let display = self.fit_into_rows(num_rows);
+
+        (display.total_width() <= display_width).then_some(display)
+    }
+
+    pub(crate) fn fit_into_width(self, display_width: usize) -> Option<Display<'grid, D>> {
         if self.cells.is_empty() {
             return Some(Display {
-                dimentions: Dimentions::one_row(0),
+                dimentions: Dimentions::one_row(Vec::new()),
                 grid: self,
+                width_limit: usize::MAX,
             });
         }
-        let max_cell_width: usize = self.cells.iter().map(|cell| cell.width).max().unwrap_or(0);
+        // GridCells wider than width_limit are truncated to width_limit
+        let width_limit = self.options.width_limit(display_width)?;
+        let max_columns = self.options.max_columns();
+        let max_cell_width: usize = self.cell_widths(width_limit).max().unwrap_or(0);
 
         // return `None` if there is a `DisplayCell` whose width is
         // greator than or equal than display_width
         if max_cell_width >= display_width {
             None
+        } else if self.options.layout_strategy == LayoutStrategy::Gnu {
+            let num_columns = search::gnu_num_columns(
+                self.options.direction,
+                self.cell_widths(width_limit),
+                self.options.seperator_width,
+                display_width,
+                max_columns,
+            );
+
+            Some(self.display_with_columns(num_columns, width_limit))
         } else {
-            let total_width: usize = (self.cells.iter().map(|cell| cell.width).sum::<usize>())
-                + (self.total_cell_count() - 1) * self.seperator_width;
+            let total_width: usize = (self.cell_widths(width_limit).sum::<usize>())
+                + (self.total_cell_count() - 1) * self.options.seperator_width;
 
             // if total width width is <= display_width, display all `DisplayCell` in one row
-            if total_width <= display_width {
+            if total_width <= display_width && self.total_cell_count() <= max_columns {
                 Some(Display {
-                    dimentions: Dimentions::one_row(self.total_cell_count()),
+                    dimentions: Dimentions::one_row(self.cell_widths(width_limit).collect()),
                     grid: self,
+                    width_limit,
                 })
             } else {
-                Some(self.internal_fit_into_width(max_cell_width, display_width))
+                Some(self.internal_fit_into_width(max_cell_width, display_width, width_limit))
             }
         }
     }
@@ -166,109 +828,710 @@ impl<'cells, 'seperator, D: fmt::Display> Grid<'cells, 'seperator, D> {
         &self,
         max_cell_width: usize,
         display_width: usize,
-    ) -> Display<'_, D> {
-        let total_cell_count = self.total_cell_count();
+        width_limit: usize,
+    ) -> Display<'grid, D> {
         // choose the starting num_columns by using the max DisplayCell width
         // with seperator spaces
-        let mut num_columns = display_width / (max_cell_width + self.seperator_width);
-        let mut dimentions = self.calculate_dimentions(num_columns);
+        let max_columns = self.options.max_columns();
+        let num_columns = (display_width / (max_cell_width + self.options.seperator_width))
+            .max(1)
+            .min(max_columns);
 
-        // increase the num_columns to find the dimentions where grid is most well packed
-        loop {
-            num_columns += 1;
-            let new_dimentions = self.calculate_dimentions(num_columns);
-
-            // stop increasing num_columns if total width is greator than display_width
-            if new_dimentions.total_width(self.seperator_width) > display_width {
-                break;
-            }
-            // use new_dimentions as dimentions if it is well packed
-            else if new_dimentions.is_well_packed(total_cell_count, dimentions.num_rows) {
-                dimentions = new_dimentions;
+        // the width of a column with anchored cells depends on more than its
+        // widest cell, so only use `WidthSearch` when there are no anchored cells
+        let mut width_search = if self.has_anchored_cells() {
+            None
+        } else {
+            Some(WidthSearch::new(
+                self.options.direction,
+                self.cell_widths(width_limit).collect(),
+                max_cell_width,
+            ))
+        };
+        let mut fits = |num_columns: usize| match &mut width_search {
+            Some(width_search) => display_width
+                .checked_sub((num_columns - 1) * self.options.seperator_width)
+                .is_some_and(|max_total_width| width_search.fits(num_columns, max_total_width)),
+            None => {
+                self.calculate_dimentions(num_columns, width_limit)
+                    .total_width(self.options.seperator_width)
+                    <= display_width
             }
+        };
+        let num_columns =
+            search::well_packed_num_columns(self.total_cell_count(), num_columns, |num_columns| {
+                num_columns <= max_columns && fits(num_columns)
+            });
+
+        self.display_with_columns(num_columns, width_limit)
+    }
+
+    pub(crate) fn display_with_columns(
+        self,
+        num_columns: usize,
+        width_limit: usize,
+    ) -> Display<'grid, D> {
+        Display {
+            dimentions: self.calculate_dimentions(num_columns, width_limit),
+            grid: self,
+            width_limit,
         }
+    }
+
+    pub(crate) fn display_with_layout(self, layout: Layout) -> Display<'grid, D> {
+        let Layout {
+            num_rows,
+            column_widths,
+            mut anchor_widths,
+            truncation_width,
+        } = layout;
+        anchor_widths.resize(column_widths.len(), 0);
 
         Display {
-            dimentions: dimentions,
+            dimentions: Dimentions {
+                // rows after the last GridCell are empty, so they are not written
+                num_rows: num_rows.min(self.total_cell_count()),
+                column_widths,
+                anchor_widths,
+            },
             grid: self,
+            width_limit: truncation_width.unwrap_or(usize::MAX),
         }
     }
 
-    fn calculate_dimentions(&self, num_columns: usize) -> Dimentions {
+    /// Returns the width of each GridCell, where GridCells wider than
+    /// `width_limit` are truncated to `width_limit`
+    fn cell_widths(self, width_limit: usize) -> impl ExactSizeIterator<Item = usize> + 'grid {
+        self.cells
+            .iter()
+            .map(move |cell| self.options.column_width(cell.total_width(), width_limit))
+    }
+
+    pub(crate) fn has_anchored_cells(self) -> bool {
+        let is_anchored = |alignment: &Alignment| matches!(alignment, Alignment::Anchored(_));
+
+        self.cells.iter().any(|cell| is_anchored(&cell.alignment))
+            || self
+                .options
+                .column_alignments
+                .iter()
+                .flatten()
+                .any(is_anchored)
+    }
+
+    fn calculate_dimentions(self, num_columns: usize, width_limit: usize) -> Dimentions {
         let num_rows = usize_div_ceil(self.total_cell_count(), num_columns);
+
+        self.calculate_dimentions_with_rows(num_rows, num_columns, width_limit)
+    }
+
+    /// Calculates the dimentions with both the number of rows and columns chosen,
+    /// where every GridCell must fit into `num_rows` rows of `num_columns` columns
+    fn calculate_dimentions_with_rows(
+        self,
+        num_rows: usize,
+        num_columns: usize,
+        width_limit: usize,
+    ) -> Dimentions {
         let mut column_widths: Vec<usize> = vec![0; num_columns];
+        // widths before and from the anchor of anchored cells in each column
+        let mut before_anchor_widths: Vec<usize> = vec![0; num_columns];
+        let mut anchor_widths: Vec<usize> = vec![0; num_columns];
 
         for (cell_index, cell) in self.cells.iter().enumerate() {
-            let column_index = match self.direction {
+            let column_index = match self.options.direction {
                 Direction::LeftToRight => cell_index % num_columns,
                 Direction::TopToBottom => cell_index / num_rows,
             };
+            let cell_width = cell.total_width().min(width_limit);
 
-            column_widths[column_index] = column_widths[column_index].max(cell.width);
+            column_widths[column_index] = column_widths[column_index]
+                .max(self.options.column_width(cell.total_width(), width_limit));
+            if let Alignment::Anchored(offset) = self.cell_alignment(cell, column_index) {
+                let offset = offset.min(cell_width);
+                before_anchor_widths[column_index] = before_anchor_widths[column_index].max(offset);
+                anchor_widths[column_index] = anchor_widths[column_index].max(cell_width - offset);
+            }
+        }
+
+        // anchored cells in a column need room for the widest part before
+        // the anchor and the widest part from the anchor
+        for (column_width, (before_anchor_width, anchor_width)) in column_widths
+            .iter_mut()
+            .zip(before_anchor_widths.iter().zip(anchor_widths.iter()))
+        {
+            *column_width = (*column_width).max(before_anchor_width + anchor_width);
         }
 
         Dimentions {
-            num_rows: num_rows,
-            column_widths: column_widths,
+            num_rows,
+            column_widths,
+            anchor_widths,
         }
     }
 }
 
-/// The displayable represntation of [`Grid`](struct.Grid.html)
+/// The displayable represntation of [`Grid`](struct.Grid.html) and [`OwnedGrid`](struct.OwnedGrid.html)
 #[derive(Debug)]
 pub struct Display<'grid, D: fmt::Display> {
     dimentions: Dimentions,
-    grid: &'grid Grid<'grid, 'grid, D>,
+    grid: GridView<'grid, D>,
+    /// GridCells wider than width_limit are truncated when written
+    width_limit: usize,
+}
+
+impl<D: fmt::Display> Display<'_, D> {
+    /// Returns the number of rows in the grid
+    pub fn num_rows(&self) -> usize {
+        self.dimentions.num_rows
+    }
+
+    /// Returns the number of columns in the grid which contain a GridCell
+    ///
+    /// With [`Direction::TopToBottom`](enum.Direction.html#variant.TopToBottom),
+    /// this can be fewer than the number of columns the grid was fit into.
+    pub fn num_columns(&self) -> usize {
+        let num_columns = self.dimentions.column_widths.len();
+        let cell_count = self.grid.total_cell_count();
+
+        match self.grid.options.direction {
+            _ if self.dimentions.num_rows == 0 => 0,
+            Direction::LeftToRight => num_columns.min(cell_count),
+            Direction::TopToBottom => {
+                num_columns.min(usize_div_ceil(cell_count, self.dimentions.num_rows))
+            }
+        }
+    }
+
+    /// Returns the width of each column which contains a GridCell, excluding seperators
+    pub fn column_widths(&self) -> &[usize] {
+        &self.dimentions.column_widths[..self.num_columns()]
+    }
+
+    /// Returns the total width of the grid as it is written, which is the sum of
+    /// the column widths and the seperators between them
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use nls_term_grid::{Direction, Grid, GridCell};
+    ///
+    /// let cells = [
+    ///     GridCell::from(String::from("file1")),
+    ///     GridCell::from(String::from("file10")),
+    ///     GridCell::from(String::from("file100")),
+    /// ];
+    ///
+    /// let grid = Grid::new("  ", Direction::TopToBottom, &cells);
+    /// let display = grid.fit_into_width(20).unwrap();
+    ///
+    /// assert_eq!(display.num_rows(), 2);
+    /// assert_eq!(display.column_widths(), &[6, 7]);
+    /// assert_eq!(display.total_width(), 15);
+    /// ```
+    pub fn total_width(&self) -> usize {
+        let column_widths = self.column_widths();
+
+        column_widths.iter().sum::<usize>()
+            + column_widths.len().saturating_sub(1) * self.grid.options.seperator_width
+    }
+
+    /// Returns the computed layout of the grid, which can be rendered again with
+    /// [`Grid::display_with_layout`](struct.Grid.html#method.display_with_layout)
+    pub fn layout(&self) -> Layout {
+        Layout {
+            num_rows: self.dimentions.num_rows,
+            column_widths: self.column_widths().to_vec(),
+            anchor_widths: self.dimentions.anchor_widths[..self.num_columns()].to_vec(),
+            truncation_width: (self.width_limit != usize::MAX).then_some(self.width_limit),
+        }
+    }
+
+    /// Returns an iterator over the position of every GridCell, row by row
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use nls_term_grid::{Direction, Grid, GridCell, PositionedCell};
+    ///
+    /// let cells = [
+    ///     GridCell::from(String::from("file1")),
+    ///     GridCell::from(String::from("file10")),
+    ///     GridCell::from(String::from("file100")),
+    /// ];
+    ///
+    /// let grid = Grid::new("  ", Direction::TopToBottom, &cells);
+    /// let display = grid.fit_into_columns(2);
+    /// let positioned_cells: Vec<PositionedCell> = display.positioned_cells().collect();
+    ///
+    /// assert_eq!(
+    ///     positioned_cells[1],
+    ///     PositionedCell { index: 2, row: 0, column: 1, x: 8, width: 7 }
+    /// );
+    /// ```
+    pub fn positioned_cells(&self) -> PositionedCells<'_> {
+        PositionedCells {
+            dimentions: &self.dimentions,
+            direction: self.grid.options.direction,
+            cell_count: self.grid.total_cell_count(),
+            row: 0,
+            column: 0,
+            x: 0,
+            seperator_width: self.grid.options.seperator_width,
+        }
+    }
+}
+
+impl<D: fmt::Display> Display<'_, D> {
+    /// Returns an iterator over the position of every GridCell in `row`
+    fn row_positioned_cells(&self, row: usize) -> impl Iterator<Item = PositionedCell> + '_ {
+        PositionedCells {
+            row,
+            ..self.positioned_cells()
+        }
+        .take_while(move |positioned_cell| positioned_cell.row == row)
+    }
+
+    /// Writes `rows`, where every line of a row is written between the
+    /// left and right side of `frame` if there is one
+    pub(crate) fn write_rows<F: fmt::Write>(
+        &self,
+        f: &mut F,
+        rows: Range<usize>,
+        frame: Option<&RowFrame<'_>>,
+    ) -> fmt::Result {
+        self.write_rows_with_buffer(f, rows, frame, &mut String::new())
+    }
+
+    /// Writes `rows` like [`write_rows`](Self::write_rows), where `buffer` is used
+    /// to split the contents of GridCells with more than one line into lines
+    fn write_rows_with_buffer<F: fmt::Write>(
+        &self,
+        f: &mut F,
+        rows: Range<usize>,
+        frame: Option<&RowFrame<'_>>,
+        buffer: &mut String,
+    ) -> fmt::Result {
+        for row in rows {
+            // the height of a row is the height of its tallest cell
+            let row_height = self
+                .row_positioned_cells(row)
+                .map(|positioned_cell| self.grid.cells[positioned_cell.index].num_lines())
+                .max()
+                .unwrap_or(1);
+
+            if row_height == 1 {
+                self.write_row(f, row, frame)?;
+            } else {
+                self.write_multi_line_row(f, row, row_height, frame, buffer)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes a row where every GridCell has one line
+    fn write_row<F: fmt::Write>(
+        &self,
+        f: &mut F,
+        row: usize,
+        frame: Option<&RowFrame<'_>>,
+    ) -> fmt::Result {
+        let mut positioned_cells = self.row_positioned_cells(row).peekable();
+
+        if let Some(frame) = frame {
+            f.write_str(frame.left)?;
+        }
+        while let Some(positioned_cell) = positioned_cells.next() {
+            let cell = &self.grid.cells[positioned_cell.index];
+            // truncation can leave a GridCell narrower than the width limit,
+            // so it is padded by the width of its truncated contents
+            let truncated = (cell.total_width() > self.width_limit)
+                .then(|| cell.truncated(self.width_limit, self.grid.options.truncation()));
+            let cell_width = truncated
+                .as_ref()
+                .map_or(cell.total_width(), GridCell::total_width);
+
+            let alignment = self.grid.cell_alignment(cell, positioned_cell.column);
+            let (left_pad_width, right_pad_width) = alignment.pad_widths(
+                cell_width,
+                positioned_cell.width,
+                self.dimentions.anchor_widths[positioned_cell.column],
+            );
+            let is_last_in_row = positioned_cells.peek().is_none();
+            // if the cell is the last cell in the row and is not right aligned, the cell does
+            // not need to be written with trailing padding and does not need be written with
+            // seperator spaces
+            let is_trimmed = is_last_in_row && frame.is_none() && alignment != Alignment::Right;
+            let right_pad_width = if is_trimmed { 0 } else { right_pad_width };
+
+            match truncated {
+                Some(truncated) => truncated.write(f, left_pad_width, right_pad_width)?,
+                None => cell.write(f, left_pad_width, right_pad_width)?,
+            }
+
+            if is_last_in_row {
+                if let Some(frame) = frame {
+                    f.write_str(frame.right)?;
+                } else if !is_trimmed {
+                    f.write_str(&self.grid.options.seperator)?;
+                }
+                // write a '\n' after the last cell in row
+                writeln!(f)?;
+            } else {
+                f.write_str(&self.grid.options.seperator)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes a row containing GridCells with more than one line,
+    /// one line of every GridCell at a time
+    fn write_multi_line_row<F: fmt::Write>(
+        &self,
+        f: &mut F,
+        row: usize,
+        row_height: usize,
+        frame: Option<&RowFrame<'_>>,
+        buffer: &mut String,
+    ) -> fmt::Result {
+        let vertical_alignment = self.grid.options.vertical_alignment;
+
+        for line_index in 0..row_height {
+            // the index of the line of a GridCell written on this line, if there is one
+            let cell_line_index = |positioned_cell: &PositionedCell| {
+                let num_lines = self.grid.cells[positioned_cell.index].num_lines();
+                line_index
+                    .checked_sub(vertical_alignment.top_pad_height(num_lines, row_height))
+                    .filter(|cell_line_index| *cell_line_index < num_lines)
+            };
+
+            // cells after the last cell with a line on this line are not written,
+            // so that lines are not written with trailing spaces
+            let line_cell_count = match frame {
+                Some(frame) => {
+                    f.write_str(frame.left)?;
+                    usize::MAX
+                }
+                None => self
+                    .row_positioned_cells(row)
+                    .enumerate()
+                    .filter(|(_, positioned_cell)| cell_line_index(positioned_cell).is_some())
+                    .last()
+                    .map_or(0, |(index, _)| index + 1),
+            };
+            let mut positioned_cells = self
+                .row_positioned_cells(row)
+                .take(line_cell_count)
+                .peekable();
+
+            while let Some(positioned_cell) = positioned_cells.next() {
+                let is_last_in_line = positioned_cells.peek().is_none();
+
+                match cell_line_index(&positioned_cell) {
+                    Some(cell_line_index) => self.write_cell_line(
+                        f,
+                        &positioned_cell,
+                        cell_line_index,
+                        is_last_in_line && frame.is_none(),
+                        buffer,
+                    )?,
+                    None => write_padding(f, positioned_cell.width)?,
+                }
+
+                if !is_last_in_line {
+                    f.write_str(&self.grid.options.seperator)?;
+                }
+            }
+            if let Some(frame) = frame {
+                f.write_str(frame.right)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the line at `line_index` of the GridCell at `positioned_cell`,
+    /// without trailing padding if `is_trimmed`
+    ///
+    /// Contents with more than one line are formatted into `buffer` to find the line,
+    /// so that GridCells are written without allocating unless they are truncated.
+    fn write_cell_line<F: fmt::Write>(
+        &self,
+        f: &mut F,
+        positioned_cell: &PositionedCell,
+        line_index: usize,
+        is_trimmed: bool,
+        buffer: &mut String,
+    ) -> fmt::Result {
+        let cell = &self.grid.cells[positioned_cell.index];
+        let is_truncated = cell.total_width() > self.width_limit;
+
+        if cell.num_lines() == 1 && !is_truncated {
+            return self.write_line(
+                f,
+                positioned_cell,
+                &cell.contents,
+                cell.total_width(),
+                true,
+                is_trimmed,
+            );
+        }
+
+        buffer.clear();
+        write!(buffer, "{}", cell.contents)?;
+        let line = buffer.split('\n').nth(line_index).unwrap_or_default();
+        // truncated lines are padded by their own width
+        let truncated = if is_truncated {
+            truncate::truncate(
+                line,
+                self.width_limit.saturating_sub(cell.icon_width()),
+                self.grid.options.truncation(),
+            )
+        } else {
+            None
+        };
+
+        match truncated {
+            Some((line, line_width)) => self.write_line(
+                f,
+                positioned_cell,
+                line,
+                line_width + cell.icon_width(),
+                line_index == 0,
+                is_trimmed,
+            ),
+            None => self.write_line(
+                f,
+                positioned_cell,
+                line,
+                ansi_width(line) + cell.icon_width(),
+                line_index == 0,
+                is_trimmed,
+            ),
+        }
+    }
+
+    /// Writes `line` of the GridCell at `positioned_cell` padded to the width
+    /// of its column, where `line_width` includes the icon
+    fn write_line<F: fmt::Write, L: fmt::Display>(
+        &self,
+        f: &mut F,
+        positioned_cell: &PositionedCell,
+        line: L,
+        line_width: usize,
+        is_first_line: bool,
+        is_trimmed: bool,
+    ) -> fmt::Result {
+        let cell = &self.grid.cells[positioned_cell.index];
+        let alignment = self.grid.cell_alignment(cell, positioned_cell.column);
+        let (left_pad_width, right_pad_width) = alignment.pad_widths(
+            line_width,
+            positioned_cell.width,
+            self.dimentions.anchor_widths[positioned_cell.column],
+        );
+        let right_pad_width = if is_trimmed { 0 } else { right_pad_width };
+
+        write_padding(f, left_pad_width)?;
+        cell.write_icon(f, is_first_line)?;
+        write_decorated(f, cell.style, cell.hyperlink.as_deref(), line)?;
+        write_padding(f, right_pad_width)
+    }
 }
 
 impl<D: fmt::Display> fmt::Display for Display<'_, D> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let total_cell_count = self.grid.total_cell_count();
-        if total_cell_count == 0 {
+        if self.grid.total_cell_count() == 0 {
             return writeln!(f);
         }
-        let mut cell_count: usize = 0;
-        let last_cell_index = total_cell_count - 1;
+
+        self.write_rows(f, 0..self.dimentions.num_rows, None)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<D: fmt::Display> Display<'_, D> {
+    /// Writes the grid to `writer`, producing the same output as the
+    /// [`fmt::Display`](https://doc.rust-lang.org/core/fmt/trait.Display.html) implementation
+    ///
+    /// Every row is formatted into a buffer which is reused for every row, and then
+    /// written to `writer` with a single call to `write_all`, so writing a grid does
+    /// not allocate for every GridCell. GridCells with more than one line are split
+    /// into lines in a second reused buffer, and only GridCells which are truncated
+    /// are allocated when written. Wrap `writer` in a
+    /// [`BufWriter`](https://doc.rust-lang.org/std/io/struct.BufWriter.html)
+    /// to also reduce the number of writes for grids with many rows.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use nls_term_grid::{Direction, Grid, GridCell};
+    ///
+    /// let cells = [
+    ///     GridCell::from(String::from("file1")),
+    ///     GridCell::from(String::from("file10")),
+    ///     GridCell::from(String::from("file100")),
+    /// ];
+    /// let grid = Grid::new("  ", Direction::TopToBottom, &cells);
+    /// let display = grid.fit_into_width(20).unwrap();
+    ///
+    /// let mut output: Vec<u8> = Vec::new();
+    /// display.write_to(&mut output).unwrap();
+    ///
+    /// assert_eq!(output, b"file1   file100\nfile10\n");
+    /// ```
+    pub fn write_to<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        if self.grid.total_cell_count() == 0 {
+            return writer.write_all(b"\n");
+        }
+
+        let mut buffer = String::new();
+        let mut contents_buffer = String::new();
+        for row in 0..self.dimentions.num_rows {
+            buffer.clear();
+            self.write_rows_with_buffer(&mut buffer, row..row + 1, None, &mut contents_buffer)
+                .map_err(|_| std::io::Error::new(std::io::ErrorKind::Other, "formatter error"))?;
+            writer.write_all(buffer.as_bytes())?;
+        }
+        Ok(())
+    }
+}
+
+/// The computed layout of a [`Display`](struct.Display.html)
+///
+/// A layout can be stored, or sent to another process with the `serde` feature,
+/// and rendered with [`Grid::display_with_layout`](struct.Grid.html#method.display_with_layout)
+/// without computing the layout again.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Layout {
+    /// The number of rows
+    pub num_rows: usize,
+    /// The width of each column, excluding seperators
+    pub column_widths: Vec<usize>,
+    /// The width from the anchor to the right edge of each column,
+    /// used to line up GridCells with `Alignment::Anchored`
+    pub anchor_widths: Vec<usize>,
+    /// GridCells wider than the truncation width are truncated when written
+    pub truncation_width: Option<usize>,
+}
+
+/// The left and right side written around every line of a row
+#[derive(Debug)]
+pub(crate) struct RowFrame<'a> {
+    pub(crate) left: &'a str,
+    pub(crate) right: &'a str,
+}
+
+/// The position of a GridCell in a [`Display`](struct.Display.html)
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct PositionedCell {
+    /// The index of the GridCell in the cells of the Grid
+    pub index: usize,
+    /// The row the GridCell is written in
+    pub row: usize,
+    /// The column the GridCell is written in
+    pub column: usize,
+    /// The offset of the column from the start of the row in columns
+    pub x: usize,
+    /// The width the GridCell is padded to, which is the width of its column
+    pub width: usize,
+}
+
+/// Iterator over the [`PositionedCell`](struct.PositionedCell.html)s of a
+/// [`Display`](struct.Display.html) in the order they are written
+///
+/// Created by [`Display::positioned_cells`](struct.Display.html#method.positioned_cells)
+#[derive(Debug, Clone)]
+pub struct PositionedCells<'display> {
+    dimentions: &'display Dimentions,
+    direction: Direction,
+    cell_count: usize,
+    row: usize,
+    column: usize,
+    x: usize,
+    seperator_width: usize,
+}
+
+impl Iterator for PositionedCells<'_> {
+    type Item = PositionedCell;
+
+    fn next(&mut self) -> Option<Self::Item> {
         let num_columns = self.dimentions.column_widths.len();
-        let last_column_index = num_columns - 1;
-
-        for row_index in 0..self.dimentions.num_rows {
-            for column_index in 0..num_columns {
-                let cell_index = match self.grid.direction {
-                    Direction::LeftToRight => row_index * num_columns + column_index,
-                    Direction::TopToBottom => row_index + self.dimentions.num_rows * column_index,
-                };
-
-                // if the cell_index is greator than last_cell_index,
-                // continue to next loop iteration
-                if cell_index > last_cell_index {
-                    continue;
-                }
 
-                cell_count += 1;
-                let cell = &self.grid.cells[cell_index];
-
-                // if (the current column is the last column or is the last cell)
-                // and the cell is left aligned, the cell does not need to be
-                // written with padding and does not need be written with seperator spaces
-                if ((column_index == last_column_index) || (cell_count == total_cell_count))
-                    && cell.alignment == Alignment::Left
-                {
-                    write!(f, "{}", cell.contents)?;
-                } else {
-                    cell.write(f, self.dimentions.column_widths[column_index])?;
-                    write!(f, "{}", self.grid.seperator)?;
+        while self.row < self.dimentions.num_rows {
+            if self.column == num_columns {
+                self.row += 1;
+                self.column = 0;
+                self.x = 0;
+                continue;
+            }
+
+            // the index is checked, as the dimentions can come from a Layout
+            // which was not computed for the GridCells
+            let index = match self.direction {
+                Direction::LeftToRight => self
+                    .row
+                    .checked_mul(num_columns)
+                    .and_then(|index| index.checked_add(self.column)),
+                Direction::TopToBottom => self
+                    .dimentions
+                    .num_rows
+                    .checked_mul(self.column)
+                    .and_then(|index| index.checked_add(self.row)),
+            };
+            let column = self.column;
+            let x = self.x;
+            let width = self.dimentions.column_widths[column];
+
+            self.column += 1;
+            self.x = x.saturating_add(width).saturating_add(self.seperator_width);
+
+            // the remaining columns of the row do not contain a cell
+            // if the index is greator than the index of the last cell
+            match index {
+                Some(index) if index < self.cell_count => {
+                    return Some(PositionedCell {
+                        index,
+                        row: self.row,
+                        column,
+                        x,
+                        width,
+                    });
                 }
+                _ => self.column = num_columns,
             }
-            // write a '\n' after the last column in row
-            writeln!(f)?;
         }
-        Ok(())
+
+        None
+    }
+}
+
+/// Indicates how [`Grid::fit_into_width`](struct.Grid.html#method.fit_into_width)
+/// chooses the number of columns
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LayoutStrategy {
+    /// Chooses the number of columns where the grid is most well packed
+    WellPacked,
+    /// Chooses the number of columns the same way GNU `ls -C` and `ls -x` do
+    ///
+    /// Every candidate number of columns is checked, with each column being at
+    /// least one column wider than the seperator and the total width being
+    /// strictly less than the display width. The most columns that fit is chosen.
+    /// With a two space seperator, the output is the same as GNU `ls` with `--tabsize=0`.
+    Gnu,
+}
+
+impl Default for LayoutStrategy {
+    #[inline]
+    fn default() -> Self {
+        Self::WellPacked
     }
 }
 
 /// Indicates direction GridCells should be written in
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Direction {
     /// Writes GridCells from left to right, like a typewriter
     LeftToRight,
@@ -287,30 +1550,46 @@ impl Default for Direction {
 struct Dimentions {
     num_rows: usize,
     column_widths: Vec<usize>,
+    /// The width from the anchor to the right edge of each column,
+    /// used to line up cells with `Alignment::Anchored`
+    anchor_widths: Vec<usize>,
 }
 
 impl Dimentions {
     pub fn total_width(&self, spaces: usize) -> usize {
-        self.column_widths.iter().sum::<usize>() + ((self.column_widths.len() - 1) * spaces)
+        self.column_widths.iter().sum::<usize>()
+            + (self.column_widths.len().saturating_sub(1) * spaces)
     }
 
     /// For dimentions to be well packed, the following must occur:
     /// 1. the last column must have less than or equal to the number of rows
     /// 2. there should be as few columns as possible, this is done by checking if
-    ///     the current number of rows chosen to be used is the same as the previous
-    ///     well packed dimentions. If it is the same, the previous well packed dimentions
-    ///     is more well packed due to it having fewer columns
+    ///    the current number of rows chosen to be used is the same as the previous
+    ///    well packed dimentions. If it is the same, the previous well packed dimentions
+    ///    is more well packed due to it having fewer columns
+    ///
+    /// Being well packed only depends on the number of columns and rows, so it is
+    /// checked before calculating the column widths of the dimentions.
     #[inline]
-    pub fn is_well_packed(&self, cell_count: usize, previous_num_rows: usize) -> bool {
-        let last_col_cell_count = cell_count % (self.column_widths.len() - 1);
+    pub fn is_well_packed(
+        num_columns: usize,
+        num_rows: usize,
+        cell_count: usize,
+        previous_num_rows: usize,
+    ) -> bool {
+        let last_col_cell_count = cell_count % (num_columns - 1);
 
-        (last_col_cell_count <= self.num_rows) && (self.num_rows != previous_num_rows)
+        (last_col_cell_count <= num_rows) && (num_rows != previous_num_rows)
     }
 
-    pub fn one_row(cell_count: usize) -> Self {
+    /// Dimentions with every cell in one row, where each column contains one cell
+    pub fn one_row(column_widths: Vec<usize>) -> Self {
+        let anchor_widths = vec![0; column_widths.len()];
+
         Self {
             num_rows: 1,
-            column_widths: vec![0; cell_count],
+            column_widths,
+            anchor_widths,
         }
     }
 }
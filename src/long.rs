@@ -0,0 +1,161 @@
+use alloc::borrow::Cow;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::{Alignment, Direction, GridCell, GridOptions, GridView};
+
+/// A long listing similar to `ls -l`, where every row is written on its own
+/// line with every column aligned
+///
+/// Columns are seperated by one space and the last column is written without
+/// trailing padding, like GNU `ls -l`. By default, the columns are aligned for
+/// the permissions, link count, owner, group, size, date and name columns of
+/// `ls -l`, where the link count and size are right aligned and the other
+/// columns are left aligned. Device numbers such as `8, 1` can be lined up with
+/// [`GridCell::with_anchor`](struct.GridCell.html#method.with_anchor).
+///
+/// ## Example
+///
+/// ```
+/// use nls_term_grid::{GridCell, LongListing};
+///
+/// let rows = [
+///     ["-rw-r--r--", "1", "root", "root", "0", "2024-01-01 00:00", "a"],
+///     ["-rw-r--r--", "1", "root", "root", "5000", "2024-01-01 00:00", "big"],
+///     ["drwxr-xr-x", "12", "root", "root", "4096", "2024-01-01 00:00", "d"],
+/// ];
+/// let mut listing: LongListing<String> = LongListing::new().with_total(20);
+/// for row in rows {
+///     listing.push_row(row.iter().map(|field| GridCell::from(field.to_string())));
+/// }
+///
+/// assert_eq!(
+///     listing.to_string(),
+///     "total 20\n\
+///      -rw-r--r--  1 root root    0 2024-01-01 00:00 a\n\
+///      -rw-r--r--  1 root root 5000 2024-01-01 00:00 big\n\
+///      drwxr-xr-x 12 root root 4096 2024-01-01 00:00 d\n"
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct LongListing<D: fmt::Display> {
+    cells: Vec<GridCell<D>>,
+    num_columns: usize,
+    options: GridOptions<'static>,
+    total: Option<String>,
+}
+
+impl<D: fmt::Display> Default for LongListing<D> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<D: fmt::Display> LongListing<D> {
+    /// Create a new LongListing without rows, aligned for the columns of `ls -l`
+    pub fn new() -> Self {
+        let mut options = GridOptions::new(Cow::Borrowed(" "), Direction::LeftToRight);
+        options.column_alignments = vec![
+            Some(Alignment::Left),
+            Some(Alignment::Right),
+            Some(Alignment::Left),
+            Some(Alignment::Left),
+            Some(Alignment::Right),
+            Some(Alignment::Left),
+            Some(Alignment::Left),
+        ];
+
+        Self {
+            cells: Vec::new(),
+            num_columns: 0,
+            options,
+            total: None,
+        }
+    }
+
+    /// Sets the alignment of each column, replacing the alignments of `ls -l`
+    ///
+    /// Columns with `None` or without an alignment keep the alignment of each GridCell.
+    pub fn with_column_alignments<A>(mut self, column_alignments: A) -> Self
+    where
+        A: Into<Vec<Option<Alignment>>>,
+    {
+        self.options.column_alignments = column_alignments.into();
+        self
+    }
+
+    /// Sets the total written as `total <total>` before the rows,
+    /// such as the number of blocks used by the listed files
+    pub fn with_total<T: fmt::Display>(mut self, total: T) -> Self {
+        self.total = Some(total.to_string());
+        self
+    }
+
+    /// Appends a row, see [`push_row`](#method.push_row)
+    pub fn with_row<R>(mut self, row: R) -> Self
+    where
+        R: IntoIterator<Item = GridCell<D>>,
+    {
+        self.push_row(row);
+        self
+    }
+
+    /// Appends a row
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the row contains a different number of GridCells from the other rows.
+    pub fn push_row<R>(&mut self, row: R)
+    where
+        R: IntoIterator<Item = GridCell<D>>,
+    {
+        let row: Vec<GridCell<D>> = row.into_iter().collect();
+        if self.cells.is_empty() {
+            self.num_columns = row.len();
+        } else if row.len() != self.num_columns {
+            panic!(
+                "row contains {} GridCells, but the listing has {} columns",
+                row.len(),
+                self.num_columns
+            );
+        }
+        self.cells.extend(row);
+    }
+
+    /// Returns the width of each column
+    pub fn column_widths(&self) -> Vec<usize> {
+        if self.num_columns == 0 {
+            return Vec::new();
+        }
+
+        self.view()
+            .fit_into_columns(self.num_columns)
+            .column_widths()
+            .to_vec()
+    }
+
+    #[inline]
+    fn view(&self) -> GridView<'_, D> {
+        GridView {
+            cells: &self.cells,
+            options: &self.options,
+        }
+    }
+}
+
+impl<D: fmt::Display> fmt::Display for LongListing<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(total) = &self.total {
+            writeln!(f, "total {}", total)?;
+        }
+        if self.num_columns == 0 {
+            return Ok(());
+        }
+
+        let display = self.view().fit_into_columns(self.num_columns);
+        display.write_rows(f, 0..display.num_rows(), None)
+    }
+}
@@ -0,0 +1,354 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::{Color, GridCell, Style};
+
+/// The type of a file, used to choose the style of its name
+///
+/// Types such as [`Executable`](#variant.Executable) or
+/// [`StickyOtherWritable`](#variant.StickyOtherWritable) are styled as a
+/// regular file or a directory when `LS_COLORS` does not contain a style for them.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum FileType {
+    /// A regular file, key `fi`, or a file matching a `*.ext` key
+    RegularFile,
+    /// A directory, key `di`
+    Directory,
+    /// A symbolic link, key `ln`
+    Symlink,
+    /// A symbolic link whose target does not exist, key `or`
+    OrphanSymlink,
+    /// A file which does not exist, such as the target of an orphan symlink, key `mi`
+    MissingFile,
+    /// A named pipe, key `pi`
+    Fifo,
+    /// A socket, key `so`
+    Socket,
+    /// A door, key `do`
+    Door,
+    /// A block device, key `bd`
+    BlockDevice,
+    /// A character device, key `cd`
+    CharDevice,
+    /// A regular file with an execute permission, key `ex`
+    Executable,
+    /// A regular file with the setuid bit set, key `su`
+    Setuid,
+    /// A regular file with the setgid bit set, key `sg`
+    Setgid,
+    /// A regular file with file capabilities, key `ca`
+    Capability,
+    /// A regular file with more than one hard link, key `mh`
+    MultipleHardLinks,
+    /// A directory with the sticky bit set, key `st`
+    Sticky,
+    /// A directory writable by others, key `ow`
+    OtherWritable,
+    /// A directory with the sticky bit set and writable by others, key `tw`
+    StickyOtherWritable,
+}
+
+/// The keys of `LS_COLORS` for file types, in the order of `LsColors::indicators`
+const INDICATOR_KEYS: [&str; 19] = [
+    "no", "fi", "di", "ln", "or", "mi", "pi", "so", "do", "bd", "cd", "ex", "su", "sg", "ca", "mh",
+    "st", "ow", "tw",
+];
+
+/// Indexes of `LsColors::indicators`
+const NORMAL: usize = 0;
+const FILE: usize = 1;
+const DIRECTORY: usize = 2;
+const SYMLINK: usize = 3;
+const ORPHAN: usize = 4;
+const MISSING: usize = 5;
+const FIFO: usize = 6;
+const SOCKET: usize = 7;
+const DOOR: usize = 8;
+const BLOCK_DEVICE: usize = 9;
+const CHAR_DEVICE: usize = 10;
+const EXECUTABLE: usize = 11;
+const SETUID: usize = 12;
+const SETGID: usize = 13;
+const CAPABILITY: usize = 14;
+const MULTIPLE_HARD_LINKS: usize = 15;
+const STICKY: usize = 16;
+const OTHER_WRITABLE: usize = 17;
+const STICKY_OTHER_WRITABLE: usize = 18;
+
+/// The colors of the 8 standard colors, indexed by their SGR parameter minus 30
+const STANDARD_COLORS: [Color; 8] = [
+    Color::Black,
+    Color::Red,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::Magenta,
+    Color::Cyan,
+    Color::White,
+];
+
+/// The colors of the 8 bright colors, indexed by their SGR parameter minus 90
+const BRIGHT_COLORS: [Color; 8] = [
+    Color::BrightBlack,
+    Color::BrightRed,
+    Color::BrightGreen,
+    Color::BrightYellow,
+    Color::BrightBlue,
+    Color::BrightMagenta,
+    Color::BrightCyan,
+    Color::BrightWhite,
+];
+
+/// The styles of file names parsed from `LS_COLORS`, the format written by `dircolors`
+///
+/// Every entry of `LS_COLORS` is a key and a list of SGR parameters seperated by
+/// `=`, such as `di=01;34` for directories or `*.tar=01;31` for names ending with
+/// `.tar`. Entries are seperated by `:`. Parameters for blinking, hidden and
+/// crossed out text are ignored, as [`Style`](struct.Style.html) cannot contain them.
+///
+/// Like GNU `ls`, the styles of `LS_COLORS` replace the styles `ls` uses when
+/// `LS_COLORS` is not set, and the suffix of a regular file is matched against
+/// `*.ext` keys ignoring ASCII case, where later keys take precedence.
+///
+/// ## Example
+///
+/// ```
+/// use nls_term_grid::{Color, FileType, LsColors, Style};
+///
+/// let ls_colors = LsColors::parse("di=01;34:ln=target:*.tar=01;31:*.TXT=00;33");
+///
+/// assert_eq!(
+///     ls_colors.style_for("src", FileType::Directory),
+///     Some(Style::new().bold().fg(Color::Blue))
+/// );
+/// assert_eq!(
+///     ls_colors.style_for("a.tar", FileType::RegularFile),
+///     Some(Style::new().bold().fg(Color::Red))
+/// );
+/// assert_eq!(
+///     ls_colors.style_for("notes.txt", FileType::RegularFile),
+///     Some(Style::new().fg(Color::Yellow))
+/// );
+/// assert_eq!(ls_colors.style_for("README", FileType::RegularFile), None);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct LsColors {
+    indicators: [Option<Style>; INDICATOR_KEYS.len()],
+    /// The suffixes of `*.ext` keys in lowercase and their styles, in the order they were parsed
+    suffixes: Vec<(String, Option<Style>)>,
+    /// Whether `ln=target` is set
+    symlink_as_target: bool,
+}
+
+impl Default for LsColors {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LsColors {
+    /// Create the styles GNU `ls` uses when `LS_COLORS` is not set
+    pub fn new() -> Self {
+        let mut indicators = [None; INDICATOR_KEYS.len()];
+        indicators[DIRECTORY] = Some(Style::new().bold().fg(Color::Blue));
+        indicators[SYMLINK] = Some(Style::new().bold().fg(Color::Cyan));
+        indicators[FIFO] = Some(Style::new().fg(Color::Yellow));
+        indicators[SOCKET] = Some(Style::new().bold().fg(Color::Magenta));
+        indicators[DOOR] = Some(Style::new().bold().fg(Color::Magenta));
+        indicators[BLOCK_DEVICE] = Some(Style::new().bold().fg(Color::Yellow));
+        indicators[CHAR_DEVICE] = Some(Style::new().bold().fg(Color::Yellow));
+        indicators[EXECUTABLE] = Some(Style::new().bold().fg(Color::Green));
+        indicators[SETUID] = Some(Style::new().fg(Color::White).on(Color::Red));
+        indicators[SETGID] = Some(Style::new().fg(Color::Black).on(Color::Yellow));
+        indicators[STICKY] = Some(Style::new().fg(Color::White).on(Color::Blue));
+        indicators[OTHER_WRITABLE] = Some(Style::new().fg(Color::Blue).on(Color::Green));
+        indicators[STICKY_OTHER_WRITABLE] = Some(Style::new().fg(Color::Black).on(Color::Green));
+
+        Self {
+            indicators,
+            suffixes: Vec::new(),
+            symlink_as_target: false,
+        }
+    }
+
+    /// Parses `ls_colors`, in the format of the `LS_COLORS` environment variable
+    ///
+    /// Entries with an unknown key or an invalid list of parameters are ignored.
+    pub fn parse(ls_colors: &str) -> Self {
+        let mut colors = Self::new();
+
+        for entry in ls_colors.split(':') {
+            let Some((key, value)) = entry.split_once('=') else {
+                continue;
+            };
+
+            if let Some(suffix) = key.strip_prefix('*') {
+                if let Some(style) = parse_style(value) {
+                    colors.suffixes.push((suffix.to_ascii_lowercase(), style));
+                }
+            } else if key == "ln" && value == "target" {
+                colors.symlink_as_target = true;
+            } else if let Some(index) = INDICATOR_KEYS.iter().position(|k| *k == key) {
+                if let Some(style) = parse_style(value) {
+                    colors.indicators[index] = style;
+                    if index == SYMLINK {
+                        colors.symlink_as_target = false;
+                    }
+                }
+            }
+        }
+
+        colors
+    }
+
+    /// Parses the `LS_COLORS` environment variable, or returns the styles
+    /// GNU `ls` uses if it is not set
+    #[cfg(feature = "std")]
+    pub fn from_env() -> Self {
+        match std::env::var("LS_COLORS") {
+            Ok(ls_colors) => Self::parse(&ls_colors),
+            Err(_) => Self::new(),
+        }
+    }
+
+    /// Returns whether symbolic links are styled as the file they point to,
+    /// which is set with `ln=target`
+    ///
+    /// [`style_for`](#method.style_for) returns the style of a regular file for
+    /// [`FileType::Symlink`](enum.FileType.html#variant.Symlink) in that case, so
+    /// callers should look up the style with the type of the target instead.
+    pub fn symlink_as_target(&self) -> bool {
+        self.symlink_as_target
+    }
+
+    /// Returns the style of a file named `file_name` with type `file_type`,
+    /// or `None` if the name is not styled
+    pub fn style_for(&self, file_name: &str, file_type: FileType) -> Option<Style> {
+        let indicator = |index: usize| self.indicators[index];
+
+        let style = match file_type {
+            FileType::RegularFile => self.regular_file_style(file_name),
+            FileType::Directory => indicator(DIRECTORY),
+            FileType::Symlink if self.symlink_as_target => self.regular_file_style(file_name),
+            FileType::Symlink => indicator(SYMLINK),
+            FileType::OrphanSymlink => indicator(ORPHAN).or(indicator(SYMLINK)),
+            FileType::MissingFile => indicator(MISSING),
+            FileType::Fifo => indicator(FIFO),
+            FileType::Socket => indicator(SOCKET),
+            FileType::Door => indicator(DOOR),
+            FileType::BlockDevice => indicator(BLOCK_DEVICE),
+            FileType::CharDevice => indicator(CHAR_DEVICE),
+            FileType::Executable => {
+                indicator(EXECUTABLE).or_else(|| self.regular_file_style(file_name))
+            }
+            FileType::Setuid => indicator(SETUID).or_else(|| self.regular_file_style(file_name)),
+            FileType::Setgid => indicator(SETGID).or_else(|| self.regular_file_style(file_name)),
+            FileType::Capability => {
+                indicator(CAPABILITY).or_else(|| self.regular_file_style(file_name))
+            }
+            FileType::MultipleHardLinks => {
+                indicator(MULTIPLE_HARD_LINKS).or_else(|| self.regular_file_style(file_name))
+            }
+            FileType::Sticky => indicator(STICKY).or(indicator(DIRECTORY)),
+            FileType::OtherWritable => indicator(OTHER_WRITABLE).or(indicator(DIRECTORY)),
+            FileType::StickyOtherWritable => indicator(STICKY_OTHER_WRITABLE)
+                .or(indicator(OTHER_WRITABLE))
+                .or(indicator(STICKY))
+                .or(indicator(DIRECTORY)),
+        };
+
+        style
+            .or(indicator(NORMAL))
+            .filter(|style| !style.is_plain())
+    }
+
+    /// Returns a GridCell containing `file_name`, styled for `file_type`
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use nls_term_grid::{FileType, LsColors};
+    ///
+    /// let cell = LsColors::new().cell("src", FileType::Directory);
+    ///
+    /// assert_eq!(cell.width, 3);
+    /// assert!(cell.style.is_some());
+    /// ```
+    pub fn cell(&self, file_name: &str, file_type: FileType) -> GridCell<String> {
+        let cell = GridCell::from(String::from(file_name));
+
+        match self.style_for(file_name, file_type) {
+            Some(style) => cell.with_style(style),
+            None => cell,
+        }
+    }
+
+    /// Returns the style of the last matching `*.ext` key, or the style of `fi`
+    fn regular_file_style(&self, file_name: &str) -> Option<Style> {
+        self.suffixes
+            .iter()
+            .rev()
+            .find(|(suffix, _)| ends_with_ignore_ascii_case(file_name, suffix))
+            .map_or(self.indicators[FILE], |(_, style)| *style)
+    }
+}
+
+/// Returns whether `s` ends with `lowercase_suffix`, ignoring ASCII case
+fn ends_with_ignore_ascii_case(s: &str, lowercase_suffix: &str) -> bool {
+    s.len() >= lowercase_suffix.len()
+        && s.as_bytes()[s.len() - lowercase_suffix.len()..]
+            .eq_ignore_ascii_case(lowercase_suffix.as_bytes())
+}
+
+/// Parses a list of SGR parameters seperated by `;`, such as `01;38;5;208`
+///
+/// Returns `None` if `value` is not a valid list of parameters,
+/// and `Some(None)` if `value` does not set any color or text attribute.
+fn parse_style(value: &str) -> Option<Option<Style>> {
+    let mut parameters = Vec::new();
+    for parameter in value.split(';') {
+        if parameter.is_empty() {
+            parameters.push(0);
+        } else {
+            parameters.push(parameter.parse::<u8>().ok()?);
+        }
+    }
+
+    let mut style = Style::new();
+    let mut parameters = parameters.into_iter();
+    while let Some(parameter) = parameters.next() {
+        match parameter {
+            0 => style = Style::new(),
+            1 => style.bold = true,
+            2 => style.dim = true,
+            3 => style.italic = true,
+            4 => style.underline = true,
+            7 => style.reverse = true,
+            30..=37 => style.foreground = Some(STANDARD_COLORS[usize::from(parameter - 30)]),
+            38 => style.foreground = Some(parse_extended_color(&mut parameters)?),
+            39 => style.foreground = None,
+            40..=47 => style.background = Some(STANDARD_COLORS[usize::from(parameter - 40)]),
+            48 => style.background = Some(parse_extended_color(&mut parameters)?),
+            49 => style.background = None,
+            90..=97 => style.foreground = Some(BRIGHT_COLORS[usize::from(parameter - 90)]),
+            100..=107 => style.background = Some(BRIGHT_COLORS[usize::from(parameter - 100)]),
+            _ => (),
+        }
+    }
+
+    Some((!style.is_plain()).then_some(style))
+}
+
+/// Parses the parameters following `38` or `48`, either `5;n` or `2;r;g;b`
+fn parse_extended_color<I: Iterator<Item = u8>>(parameters: &mut I) -> Option<Color> {
+    match parameters.next()? {
+        5 => Some(Color::Fixed(parameters.next()?)),
+        2 => Some(Color::Rgb(
+            parameters.next()?,
+            parameters.next()?,
+            parameters.next()?,
+        )),
+        _ => None,
+    }
+}
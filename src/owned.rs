@@ -0,0 +1,198 @@
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use core::fmt;
+
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    Alignment, Direction, Display, GridCell, GridOptions, GridView, Layout, LayoutStrategy,
+    Overflow, VerticalAlignment,
+};
+
+/// A grid which owns its GridCells, so GridCells can be collected into it
+///
+/// OwnedGrid has the same options as [`Grid`](struct.Grid.html), and produces
+/// the same [`Display`](struct.Display.html). The seperator is empty and the
+/// direction is [`Direction::LeftToRight`](enum.Direction.html#variant.LeftToRight)
+/// unless set otherwise.
+///
+/// ## Example
+///
+/// ```
+/// use nls_term_grid::{Direction, GridCell, OwnedGrid};
+///
+/// let grid: OwnedGrid<String> = ["file1", "file10", "file100"]
+///     .iter()
+///     .map(|name| GridCell::from(name.to_string()))
+///     .collect::<OwnedGrid<String>>()
+///     .with_seperator("  ")
+///     .with_direction(Direction::TopToBottom);
+///
+/// assert_eq!(grid.fit_into_width(20).unwrap().to_string(), "file1   file100\nfile10\n");
+/// ```
+#[derive(Debug, Clone)]
+pub struct OwnedGrid<D: fmt::Display> {
+    cells: Vec<GridCell<D>>,
+    options: GridOptions<'static>,
+}
+
+impl<D: fmt::Display> Default for OwnedGrid<D> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<D: fmt::Display> OwnedGrid<D> {
+    /// Create a new OwnedGrid without GridCells
+    pub fn new() -> Self {
+        Self {
+            cells: Vec::new(),
+            options: GridOptions::default(),
+        }
+    }
+
+    /// Sets the seperator written between columns
+    pub fn with_seperator<S: Into<Cow<'static, str>>>(mut self, seperator: S) -> Self {
+        let seperator: Cow<'static, str> = seperator.into();
+        self.options.seperator_width = UnicodeWidthStr::width(&*seperator);
+        self.options.seperator = seperator;
+        self
+    }
+
+    /// Sets the direction GridCells are written in
+    pub fn with_direction(mut self, direction: Direction) -> Self {
+        self.options.direction = direction;
+        self
+    }
+
+    /// Sets what happens to GridCells too wide to fit into the display width,
+    /// see [`Grid::with_overflow`](struct.Grid.html#method.with_overflow)
+    pub fn with_overflow(mut self, overflow: Overflow) -> Self {
+        self.options.overflow = overflow;
+        self
+    }
+
+    /// Sets the vertical alignment of GridCells with more than one line, see
+    /// [`Grid::with_vertical_alignment`](struct.Grid.html#method.with_vertical_alignment)
+    pub fn with_vertical_alignment(mut self, vertical_alignment: VerticalAlignment) -> Self {
+        self.options.vertical_alignment = vertical_alignment;
+        self
+    }
+
+    /// Sets how the number of columns is chosen by [`fit_into_width`](#method.fit_into_width)
+    pub fn with_layout_strategy(mut self, layout_strategy: LayoutStrategy) -> Self {
+        self.options.layout_strategy = layout_strategy;
+        self
+    }
+
+    /// Sets the maximum number of columns chosen by [`fit_into_width`](#method.fit_into_width)
+    pub fn with_max_columns(mut self, max_columns: usize) -> Self {
+        self.options.max_columns = Some(max_columns);
+        self
+    }
+
+    /// Sets the minimum width of every column containing a GridCell
+    pub fn with_min_column_width(mut self, min_column_width: usize) -> Self {
+        self.options.min_column_width = min_column_width;
+        self
+    }
+
+    /// Sets the maximum width of every column, see
+    /// [`Grid::with_max_column_width`](struct.Grid.html#method.with_max_column_width)
+    pub fn with_max_column_width(mut self, max_column_width: usize) -> Self {
+        self.options.max_column_width = Some(max_column_width);
+        self
+    }
+
+    /// Overrides the alignment of GridCells in each column once the layout is known, see
+    /// [`Grid::with_column_alignments`](struct.Grid.html#method.with_column_alignments)
+    pub fn with_column_alignments<A>(mut self, column_alignments: A) -> Self
+    where
+        A: Into<Vec<Option<Alignment>>>,
+    {
+        self.options.column_alignments = column_alignments.into();
+        self
+    }
+
+    /// Appends a GridCell
+    pub fn push(&mut self, cell: GridCell<D>) {
+        self.cells.push(cell);
+    }
+
+    /// Returns the GridCells of the grid
+    pub fn cells(&self) -> &[GridCell<D>] {
+        &self.cells
+    }
+
+    /// Consumes the grid, returning its GridCells
+    pub fn into_cells(self) -> Vec<GridCell<D>> {
+        self.cells
+    }
+
+    /// Returns a displayable containing the specified number of columns
+    pub fn fit_into_columns(&self, num_columns: usize) -> Display<'_, D> {
+        self.view().fit_into_columns(num_columns)
+    }
+
+    /// Returns a displayable containing at most the specified number of rows, see
+    /// [`Grid::fit_into_rows`](struct.Grid.html#method.fit_into_rows)
+    pub fn fit_into_rows(&self, num_rows: usize) -> Display<'_, D> {
+        self.view().fit_into_rows(num_rows)
+    }
+
+    /// Returns a displayable containing at most the specified number of rows, see
+    /// [`Grid::fit_into_rows_within_width`](struct.Grid.html#method.fit_into_rows_within_width)
+    pub fn fit_into_rows_within_width(
+        &self,
+        num_rows: usize,
+        display_width: usize,
+    ) -> Option<Display<'_, D>> {
+        self.view()
+            .fit_into_rows_within_width(num_rows, display_width)
+    }
+
+    /// Returns a well packed displayable grid fitted within display width,
+    /// see [`Grid::fit_into_width`](struct.Grid.html#method.fit_into_width)
+    pub fn fit_into_width(&self, display_width: usize) -> Option<Display<'_, D>> {
+        self.view().fit_into_width(display_width)
+    }
+
+    /// Returns a displayable using a [`Layout`](struct.Layout.html) computed earlier, see
+    /// [`Grid::display_with_layout`](struct.Grid.html#method.display_with_layout)
+    pub fn display_with_layout(&self, layout: Layout) -> Display<'_, D> {
+        self.view().display_with_layout(layout)
+    }
+
+    #[inline]
+    pub(crate) fn view(&self) -> GridView<'_, D> {
+        GridView {
+            cells: &self.cells,
+            options: &self.options,
+        }
+    }
+}
+
+impl<D: fmt::Display> Extend<GridCell<D>> for OwnedGrid<D> {
+    fn extend<I: IntoIterator<Item = GridCell<D>>>(&mut self, iter: I) {
+        self.cells.extend(iter);
+    }
+}
+
+impl<D: fmt::Display> FromIterator<GridCell<D>> for OwnedGrid<D> {
+    fn from_iter<I: IntoIterator<Item = GridCell<D>>>(iter: I) -> Self {
+        Self {
+            cells: Vec::from_iter(iter),
+            options: GridOptions::default(),
+        }
+    }
+}
+
+impl<D: fmt::Display> From<Vec<GridCell<D>>> for OwnedGrid<D> {
+    fn from(cells: Vec<GridCell<D>>) -> Self {
+        Self {
+            cells,
+            options: GridOptions::default(),
+        }
+    }
+}
@@ -0,0 +1,199 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{usize_div_ceil, Dimentions, Direction};
+
+/// Answers whether a number of columns fits within a display width
+/// without calculating the width of every column
+///
+/// Cells are visited from widest to narrowest. The first cell visited in
+/// a column is the widest cell of that column, and every column not visited
+/// yet is at most as wide as the current cell, so the visit can stop as soon
+/// as the total width is known to be within or over the display width.
+#[derive(Debug)]
+pub(crate) struct WidthSearch {
+    direction: Direction,
+    /// The width of each cell, indexed by cell index
+    widths: Vec<usize>,
+    /// Cell indexes ordered from the widest to the narrowest cell
+    order: Vec<usize>,
+    /// The number of columns when each column was last visited,
+    /// used to avoid clearing the visited columns between candidates
+    visited: Vec<usize>,
+}
+
+impl WidthSearch {
+    pub(crate) fn new(direction: Direction, widths: Vec<usize>, max_width: usize) -> Self {
+        // counting sort, cell widths are bounded by the display width
+        let mut counts: Vec<usize> = vec![0; max_width + 1];
+        for width in &widths {
+            counts[*width] += 1;
+        }
+        let mut start: usize = 0;
+        for count in counts.iter_mut().rev() {
+            let next_start = start + *count;
+            *count = start;
+            start = next_start;
+        }
+        let mut order: Vec<usize> = vec![0; widths.len()];
+        for (cell_index, width) in widths.iter().enumerate() {
+            order[counts[*width]] = cell_index;
+            counts[*width] += 1;
+        }
+
+        Self {
+            direction,
+            widths,
+            order,
+            visited: Vec::new(),
+        }
+    }
+
+    /// Returns whether the sum of column widths with `num_columns` columns
+    /// is less than or equal to `max_total_width`
+    ///
+    /// `num_columns` must be greator than 1 and must be different from the
+    /// `num_columns` of the previous call.
+    pub(crate) fn fits(&mut self, num_columns: usize, max_total_width: usize) -> bool {
+        let cell_count = self.widths.len();
+        let num_rows = usize_div_ceil(cell_count, num_columns);
+        // the number of columns containing at least one cell
+        let used_columns = match self.direction {
+            Direction::LeftToRight => num_columns.min(cell_count),
+            Direction::TopToBottom => usize_div_ceil(cell_count, num_rows),
+        };
+        let min_width = self.order.last().map_or(0, |index| self.widths[*index]);
+
+        if self.visited.len() < num_columns {
+            self.visited.resize(num_columns, 0);
+        }
+
+        let mut total_width: usize = 0;
+        let mut visited_columns: usize = 0;
+
+        for cell_index in &self.order {
+            let width = self.widths[*cell_index];
+            let unvisited_columns = used_columns - visited_columns;
+
+            // every unvisited column is at most `width` wide
+            if total_width + unvisited_columns.saturating_mul(width) <= max_total_width {
+                return true;
+            }
+            // every unvisited column is at least `min_width` wide
+            if total_width + unvisited_columns.saturating_mul(min_width) > max_total_width {
+                return false;
+            }
+
+            let column_index = match self.direction {
+                Direction::LeftToRight => cell_index % num_columns,
+                Direction::TopToBottom => cell_index / num_rows,
+            };
+            if self.visited[column_index] != num_columns {
+                self.visited[column_index] = num_columns;
+                total_width += width;
+                visited_columns += 1;
+            }
+        }
+
+        total_width <= max_total_width
+    }
+}
+
+/// Returns the number of columns where a grid of `cell_count` cells is most well packed
+///
+/// Starting from `num_columns`, the number of columns is increased until `fits`
+/// returns false for it, choosing the most well packed number of columns
+/// along the way.
+pub(crate) fn well_packed_num_columns<F>(
+    cell_count: usize,
+    mut num_columns: usize,
+    mut fits: F,
+) -> usize
+where
+    F: FnMut(usize) -> bool,
+{
+    let mut num_rows = usize_div_ceil(cell_count, num_columns);
+
+    // increase the num_columns to find the dimentions where grid is most well packed
+    let mut new_num_columns = num_columns;
+    loop {
+        new_num_columns += 1;
+
+        // stop increasing num_columns if total width is greator than display_width
+        if !fits(new_num_columns) {
+            break;
+        }
+
+        // use new_num_columns as num_columns if it is well packed
+        let new_num_rows = usize_div_ceil(cell_count, new_num_columns);
+        if Dimentions::is_well_packed(new_num_columns, new_num_rows, cell_count, num_rows) {
+            num_columns = new_num_columns;
+            num_rows = new_num_rows;
+        }
+    }
+
+    num_columns
+}
+
+/// Returns the number of columns GNU `ls` chooses for cells of `widths`
+///
+/// This follows `calculate_columns` of GNU coreutils `ls.c`, where every
+/// column is at least `seperator_width + 1` wide, every column except the
+/// last includes the seperator, and a line must be shorter than `display_width`.
+/// At most `max_columns` columns are checked.
+pub(crate) fn gnu_num_columns<I>(
+    direction: Direction,
+    widths: I,
+    seperator_width: usize,
+    display_width: usize,
+    max_columns: usize,
+) -> usize
+where
+    I: ExactSizeIterator<Item = usize>,
+{
+    let cell_count = widths.len();
+    let min_column_width = seperator_width + 1;
+    let max_columns = (display_width / min_column_width)
+        .max(1)
+        .min(cell_count)
+        .min(max_columns);
+
+    // the line width and column widths of each candidate number of columns,
+    // where the candidate at index `i` has `i + 1` columns
+    let mut valid: Vec<bool> = vec![true; max_columns];
+    let mut line_widths: Vec<usize> = (1..=max_columns)
+        .map(|num_columns| num_columns * min_column_width)
+        .collect();
+    let mut column_widths: Vec<Vec<usize>> = (1..=max_columns)
+        .map(|num_columns| vec![min_column_width; num_columns])
+        .collect();
+
+    for (cell_index, width) in widths.enumerate() {
+        for i in 0..max_columns {
+            if !valid[i] {
+                continue;
+            }
+
+            let column_index = match direction {
+                Direction::LeftToRight => cell_index % (i + 1),
+                Direction::TopToBottom => cell_index / usize_div_ceil(cell_count, i + 1),
+            };
+            let width = if column_index == i {
+                width
+            } else {
+                width + seperator_width
+            };
+
+            if column_widths[i][column_index] < width {
+                line_widths[i] += width - column_widths[i][column_index];
+                column_widths[i][column_index] = width;
+                valid[i] = line_widths[i] < display_width;
+            }
+        }
+    }
+
+    (1..=max_columns)
+        .rev()
+        .find(|num_columns| valid[num_columns - 1])
+        .unwrap_or(1)
+}
@@ -0,0 +1,457 @@
+use alloc::borrow::Cow;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    search, usize_div_ceil, Alignment, Direction, Display, GridCell, GridOptions, GridView,
+    Overflow, VerticalAlignment,
+};
+
+/// A grid for a fixed display width which keeps track of its layout
+/// as GridCells are pushed one at a time
+///
+/// The layout is the same as [`Grid::fit_into_width`](struct.Grid.html#method.fit_into_width)
+/// with [`LayoutStrategy::WellPacked`](enum.LayoutStrategy.html#variant.WellPacked)
+/// would choose for the same GridCells. Instead of calculating the width of
+/// every column again for every pushed GridCell, the column widths of every
+/// number of columns which has been checked are updated with the pushed GridCell.
+/// GridCells with [`Alignment::Anchored`](enum.Alignment.html#variant.Anchored)
+/// are laid out again from scratch, as the width of their columns depends on
+/// more than the widest GridCell.
+///
+/// ## Example
+///
+/// ```
+/// use nls_term_grid::{Direction, GridCell, StreamingGrid};
+///
+/// let mut grid = StreamingGrid::new(20)
+///     .with_seperator("  ")
+///     .with_direction(Direction::TopToBottom);
+///
+/// assert!(grid.push(GridCell::from(String::from("file1"))));
+/// assert!(grid.push(GridCell::from(String::from("file10"))));
+/// assert_eq!(grid.display().unwrap().to_string(), "file1  file10\n");
+///
+/// // the GridCells no longer fit into one row
+/// assert!(grid.push(GridCell::from(String::from("file100"))));
+/// assert_eq!(grid.display().unwrap().to_string(), "file1   file100\nfile10\n");
+/// ```
+#[derive(Debug)]
+pub struct StreamingGrid<D: fmt::Display> {
+    cells: Vec<GridCell<D>>,
+    options: GridOptions<'static>,
+    display_width: usize,
+    /// GridCells wider than width_limit are truncated, `None` if no GridCell fits
+    width_limit: Option<usize>,
+    /// The width of each GridCell, truncated to width_limit
+    widths: RangeMax,
+    total_cell_width: usize,
+    max_cell_width: usize,
+    /// Whether a GridCell or a column alignment is anchored
+    has_anchored_cells: bool,
+    /// The column widths with `i + 1` columns at index `i`,
+    /// for every number of columns which has been checked
+    candidates: Vec<Option<Candidate>>,
+    layout: Option<Layout>,
+}
+
+impl<D: fmt::Display> StreamingGrid<D> {
+    /// Create a new StreamingGrid without GridCells, fitted within display width
+    ///
+    /// The seperator is empty and the direction is
+    /// [`Direction::LeftToRight`](enum.Direction.html#variant.LeftToRight)
+    /// unless set otherwise.
+    pub fn new(display_width: usize) -> Self {
+        let mut grid = Self {
+            cells: Vec::new(),
+            options: GridOptions::default(),
+            display_width,
+            width_limit: None,
+            widths: RangeMax::default(),
+            total_cell_width: 0,
+            max_cell_width: 0,
+            has_anchored_cells: false,
+            candidates: Vec::new(),
+            layout: None,
+        };
+        grid.reset();
+
+        grid
+    }
+
+    /// Sets the seperator written between columns
+    pub fn with_seperator<S: Into<Cow<'static, str>>>(mut self, seperator: S) -> Self {
+        let seperator: Cow<'static, str> = seperator.into();
+        self.options.seperator_width = UnicodeWidthStr::width(&*seperator);
+        self.options.seperator = seperator;
+        self.reset();
+        self
+    }
+
+    /// Sets the direction GridCells are written in
+    pub fn with_direction(mut self, direction: Direction) -> Self {
+        self.options.direction = direction;
+        self.reset();
+        self
+    }
+
+    /// Sets what happens to GridCells too wide to fit into the display width,
+    /// see [`Grid::with_overflow`](struct.Grid.html#method.with_overflow)
+    pub fn with_overflow(mut self, overflow: Overflow) -> Self {
+        self.options.overflow = overflow;
+        self.reset();
+        self
+    }
+
+    /// Sets the vertical alignment of GridCells with more than one line, see
+    /// [`Grid::with_vertical_alignment`](struct.Grid.html#method.with_vertical_alignment)
+    pub fn with_vertical_alignment(mut self, vertical_alignment: VerticalAlignment) -> Self {
+        self.options.vertical_alignment = vertical_alignment;
+        self
+    }
+
+    /// Sets the maximum number of columns of the layout
+    pub fn with_max_columns(mut self, max_columns: usize) -> Self {
+        self.options.max_columns = Some(max_columns);
+        self.reset();
+        self
+    }
+
+    /// Sets the minimum width of every column containing a GridCell
+    pub fn with_min_column_width(mut self, min_column_width: usize) -> Self {
+        self.options.min_column_width = min_column_width;
+        self.reset();
+        self
+    }
+
+    /// Sets the maximum width of every column, see
+    /// [`Grid::with_max_column_width`](struct.Grid.html#method.with_max_column_width)
+    pub fn with_max_column_width(mut self, max_column_width: usize) -> Self {
+        self.options.max_column_width = Some(max_column_width);
+        self.reset();
+        self
+    }
+
+    /// Overrides the alignment of GridCells in each column once the layout is known, see
+    /// [`Grid::with_column_alignments`](struct.Grid.html#method.with_column_alignments)
+    pub fn with_column_alignments<A>(mut self, column_alignments: A) -> Self
+    where
+        A: Into<Vec<Option<Alignment>>>,
+    {
+        self.options.column_alignments = column_alignments.into();
+        self.reset();
+        self
+    }
+
+    /// Appends a GridCell, returning whether the layout changed
+    ///
+    /// The layout changes when the number of rows, the number of columns or
+    /// the width of a column changes, or when the GridCells stop fitting
+    /// within the display width.
+    pub fn push(&mut self, cell: GridCell<D>) -> bool {
+        let width = self
+            .options
+            .column_width(cell.total_width(), self.width_limit.unwrap_or(0));
+        self.has_anchored_cells |= matches!(cell.alignment, Alignment::Anchored(_));
+        self.cells.push(cell);
+        self.widths.push(width);
+        self.total_cell_width += width;
+        self.max_cell_width = self.max_cell_width.max(width);
+
+        let direction = self.options.direction;
+        for candidate in self.candidates.iter_mut().flatten() {
+            candidate.push(direction, &self.widths);
+        }
+
+        self.relayout()
+    }
+
+    /// Returns the GridCells of the grid
+    pub fn cells(&self) -> &[GridCell<D>] {
+        &self.cells
+    }
+
+    /// Returns the displayable grid of the current layout
+    ///
+    /// Returns `None` if one of the GridCell contains a width greator than the display width,
+    /// unless the GridCells are truncated with [`Overflow::Truncate`](enum.Overflow.html#variant.Truncate)
+    pub fn display(&self) -> Option<Display<'_, D>> {
+        match (&self.layout, self.width_limit) {
+            (Some(layout), Some(width_limit)) if !self.cells.is_empty() => Some(
+                self.view()
+                    .display_with_columns(layout.num_columns, width_limit),
+            ),
+            _ => self.view().fit_into_width(self.display_width),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn view(&self) -> GridView<'_, D> {
+        GridView {
+            cells: &self.cells,
+            options: &self.options,
+        }
+    }
+
+    /// Calculates the layout of the GridCells again, after an option has changed
+    fn reset(&mut self) {
+        self.width_limit = self.options.width_limit(self.display_width);
+        let width_limit = self.width_limit.unwrap_or(0);
+
+        self.widths = RangeMax::default();
+        for cell in &self.cells {
+            self.widths
+                .push(self.options.column_width(cell.total_width(), width_limit));
+        }
+        self.total_cell_width = self.widths.values().iter().sum();
+        self.max_cell_width = self.widths.values().iter().copied().max().unwrap_or(0);
+        self.has_anchored_cells = self.view().has_anchored_cells();
+        self.candidates.clear();
+        self.layout = None;
+        self.relayout();
+    }
+
+    /// Chooses the layout of the GridCells, returning whether it changed
+    fn relayout(&mut self) -> bool {
+        if self.has_anchored_cells {
+            let layout = self
+                .view()
+                .fit_into_width(self.display_width)
+                .map(|display| Layout {
+                    num_rows: display.num_rows(),
+                    num_columns: display.num_columns(),
+                    column_widths: display.column_widths().to_vec(),
+                });
+            let is_changed = self.layout != layout;
+            self.layout = layout;
+
+            return is_changed;
+        }
+
+        let layout = self.num_columns().map(|num_columns| {
+            if num_columns == self.cells.len() {
+                // every GridCell is written in one row
+                (1, num_columns, self.widths.values())
+            } else {
+                let candidate = self.candidates[num_columns - 1].as_ref().unwrap();
+                (
+                    candidate.num_rows,
+                    num_columns,
+                    &candidate.column_widths[..],
+                )
+            }
+        });
+        let is_changed = match (&self.layout, layout) {
+            (Some(previous), Some((num_rows, num_columns, column_widths))) => {
+                previous.num_rows != num_rows
+                    || previous.num_columns != num_columns
+                    || previous.column_widths != column_widths
+            }
+            (None, None) => false,
+            _ => true,
+        };
+        if is_changed {
+            self.layout = layout.map(|(num_rows, num_columns, column_widths)| Layout {
+                num_rows,
+                num_columns,
+                column_widths: column_widths.to_vec(),
+            });
+        }
+
+        is_changed
+    }
+
+    /// Returns the number of columns `Grid::fit_into_width` would choose,
+    /// or `None` if the GridCells do not fit within the display width
+    fn num_columns(&mut self) -> Option<usize> {
+        let cell_count = self.cells.len();
+        let display_width = self.display_width;
+        let seperator_width = self.options.seperator_width;
+        let max_columns = self.options.max_columns();
+
+        if cell_count == 0 {
+            return Some(0);
+        }
+        if self.width_limit.is_none() || self.max_cell_width >= display_width {
+            return None;
+        }
+        if self.total_cell_width + (cell_count - 1) * seperator_width <= display_width
+            && cell_count <= max_columns
+        {
+            return Some(cell_count);
+        }
+
+        let num_columns = (display_width / (self.max_cell_width + seperator_width))
+            .max(1)
+            .min(max_columns);
+        let direction = self.options.direction;
+        let widths = &self.widths;
+        let candidates = &mut self.candidates;
+
+        let fits = |num_columns: usize| {
+            if num_columns > max_columns {
+                return false;
+            }
+            let total_width = candidate(candidates, direction, num_columns, widths).total_width;
+            display_width
+                .checked_sub((num_columns - 1) * seperator_width)
+                .is_some_and(|max_total_width| total_width <= max_total_width)
+        };
+        let num_columns = search::well_packed_num_columns(cell_count, num_columns, fits);
+        // the chosen number of columns may not have been checked
+        candidate(candidates, direction, num_columns, widths);
+
+        Some(num_columns)
+    }
+}
+
+/// Returns the candidate with `num_columns` columns, calculating its column
+/// widths if it has not been checked before
+fn candidate<'a>(
+    candidates: &'a mut Vec<Option<Candidate>>,
+    direction: Direction,
+    num_columns: usize,
+    widths: &RangeMax,
+) -> &'a Candidate {
+    if candidates.len() < num_columns {
+        candidates.resize_with(num_columns, || None);
+    }
+
+    candidates[num_columns - 1]
+        .get_or_insert_with(|| Candidate::new(direction, num_columns, widths))
+}
+
+/// The layout chosen for the GridCells pushed so far
+#[derive(Debug, PartialEq, Eq)]
+struct Layout {
+    num_rows: usize,
+    num_columns: usize,
+    column_widths: Vec<usize>,
+}
+
+/// The column widths of the GridCells pushed so far with a number of columns
+#[derive(Debug)]
+struct Candidate {
+    num_rows: usize,
+    column_widths: Vec<usize>,
+    total_width: usize,
+}
+
+impl Candidate {
+    fn new(direction: Direction, num_columns: usize, widths: &RangeMax) -> Self {
+        let cell_count = widths.values().len();
+        let num_rows = usize_div_ceil(cell_count, num_columns);
+        let mut column_widths: Vec<usize> = vec![0; num_columns];
+
+        match direction {
+            Direction::LeftToRight => {
+                for (cell_index, width) in widths.values().iter().enumerate() {
+                    let column_width = &mut column_widths[cell_index % num_columns];
+                    *column_width = (*column_width).max(*width);
+                }
+            }
+            Direction::TopToBottom => {
+                for (column_index, column_width) in column_widths.iter_mut().enumerate() {
+                    let start = (column_index * num_rows).min(cell_count);
+                    let end = (start + num_rows).min(cell_count);
+                    *column_width = widths.max(start, end);
+                }
+            }
+        }
+
+        Self {
+            num_rows,
+            total_width: column_widths.iter().sum(),
+            column_widths,
+        }
+    }
+
+    /// Updates the column widths after the last width of `widths` is pushed
+    fn push(&mut self, direction: Direction, widths: &RangeMax) {
+        let num_columns = self.column_widths.len();
+        let cell_index = widths.values().len() - 1;
+        let width = widths.values()[cell_index];
+        let num_rows = usize_div_ceil(cell_index + 1, num_columns);
+
+        let column_index = match direction {
+            Direction::LeftToRight => cell_index % num_columns,
+            // with another row, every GridCell after the first column moves to
+            // another column, so the column widths are calculated again
+            Direction::TopToBottom if num_rows != self.num_rows => {
+                *self = Self::new(direction, num_columns, widths);
+                return;
+            }
+            Direction::TopToBottom => cell_index / num_rows,
+        };
+        let column_width = &mut self.column_widths[column_index];
+        if width > *column_width {
+            self.total_width += width - *column_width;
+            *column_width = width;
+        }
+        self.num_rows = num_rows;
+    }
+}
+
+/// An append only sequence of widths, answering the maximum width of any
+/// range of widths with a segment tree
+#[derive(Debug, Default)]
+struct RangeMax {
+    values: Vec<usize>,
+    /// The maximum of the two children of each node at index `i` is at index
+    /// `i`, where the children are at index `2 * i` and `2 * i + 1` and the
+    /// leaves start at index `capacity`
+    tree: Vec<usize>,
+    capacity: usize,
+}
+
+impl RangeMax {
+    fn values(&self) -> &[usize] {
+        &self.values
+    }
+
+    fn push(&mut self, value: usize) {
+        if self.values.len() == self.capacity {
+            // double the capacity and build the tree again
+            self.capacity = (self.capacity * 2).max(1);
+            self.tree = vec![0; self.capacity * 2];
+            self.tree[self.capacity..self.capacity + self.values.len()]
+                .copy_from_slice(&self.values);
+            for index in (1..self.capacity).rev() {
+                self.tree[index] = self.tree[index * 2].max(self.tree[index * 2 + 1]);
+            }
+        }
+
+        let mut index = self.capacity + self.values.len();
+        self.values.push(value);
+        self.tree[index] = value;
+        while index > 1 {
+            index /= 2;
+            self.tree[index] = self.tree[index * 2].max(self.tree[index * 2 + 1]);
+        }
+    }
+
+    /// Returns the maximum of the values in `start..end`, or 0 if the range is empty
+    fn max(&self, start: usize, end: usize) -> usize {
+        let mut max: usize = 0;
+        let mut start = start + self.capacity;
+        let mut end = end + self.capacity;
+
+        while start < end {
+            if start % 2 == 1 {
+                max = max.max(self.tree[start]);
+                start += 1;
+            }
+            if end % 2 == 1 {
+                end -= 1;
+                max = max.max(self.tree[end]);
+            }
+            start /= 2;
+            end /= 2;
+        }
+
+        max
+    }
+}
@@ -0,0 +1,286 @@
+use core::fmt;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+/// Whether styles are written, see [`set_colors_enabled`](fn.set_colors_enabled.html)
+static COLORS_ENABLED: AtomicBool = AtomicBool::new(true);
+
+/// Sets whether the styles of GridCells are written
+///
+/// Colors are enabled by default. Disable colors when the `NO_COLOR` environment
+/// variable is set or when output is not written to a terminal, so that the same
+/// GridCells can be written without rebuilding them. GridCells are written
+/// without escape sequences for their styles while colors are disabled.
+///
+/// ## Example
+///
+/// ```
+/// use nls_term_grid::{set_colors_enabled, Color, Direction, Grid, GridCell, Style};
+///
+/// let blue = Style::new().fg(Color::Blue);
+/// let cells = [
+///     GridCell::from(String::from("dir")).with_style(blue),
+///     GridCell::from(String::from("d\ne")).with_style(blue),
+/// ];
+/// let grid = Grid::new("  ", Direction::LeftToRight, &cells);
+///
+/// assert_eq!(
+///     grid.fit_into_columns(2).to_string(),
+///     "\x1b[34mdir\x1b[0m  \x1b[34md\x1b[0m\n     \x1b[34me\x1b[0m\n"
+/// );
+///
+/// set_colors_enabled(false);
+/// assert_eq!(grid.fit_into_columns(2).to_string(), "dir  d\n     e\n");
+/// # set_colors_enabled(true);
+/// ```
+pub fn set_colors_enabled(enabled: bool) {
+    COLORS_ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Returns whether the styles of GridCells are written,
+/// see [`set_colors_enabled`](fn.set_colors_enabled.html)
+pub fn colors_enabled() -> bool {
+    COLORS_ENABLED.load(Ordering::Relaxed)
+}
+
+/// A color of the text or the background of a GridCell
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Color {
+    /// Black (code 30, or 40 as background)
+    Black,
+    /// Red (code 31, or 41 as background)
+    Red,
+    /// Green (code 32, or 42 as background)
+    Green,
+    /// Yellow (code 33, or 43 as background)
+    Yellow,
+    /// Blue (code 34, or 44 as background)
+    Blue,
+    /// Magenta (code 35, or 45 as background)
+    Magenta,
+    /// Cyan (code 36, or 46 as background)
+    Cyan,
+    /// White (code 37, or 47 as background)
+    White,
+    /// Bright black (code 90, or 100 as background)
+    BrightBlack,
+    /// Bright red (code 91, or 101 as background)
+    BrightRed,
+    /// Bright green (code 92, or 102 as background)
+    BrightGreen,
+    /// Bright yellow (code 93, or 103 as background)
+    BrightYellow,
+    /// Bright blue (code 94, or 104 as background)
+    BrightBlue,
+    /// Bright magenta (code 95, or 105 as background)
+    BrightMagenta,
+    /// Bright cyan (code 96, or 106 as background)
+    BrightCyan,
+    /// Bright white (code 97, or 107 as background)
+    BrightWhite,
+    /// A color of the 256 color palette
+    Fixed(u8),
+    /// A 24-bit truecolor
+    Rgb(u8, u8, u8),
+}
+
+impl Color {
+    /// Writes the SGR parameters of the color, where `base` is 30 for the
+    /// foreground color and 40 for the background color
+    fn write_parameters<F: fmt::Write>(self, f: &mut F, base: u8) -> fmt::Result {
+        let index = match self {
+            Self::Black => 0,
+            Self::Red => 1,
+            Self::Green => 2,
+            Self::Yellow => 3,
+            Self::Blue => 4,
+            Self::Magenta => 5,
+            Self::Cyan => 6,
+            Self::White => 7,
+            Self::BrightBlack => 60,
+            Self::BrightRed => 61,
+            Self::BrightGreen => 62,
+            Self::BrightYellow => 63,
+            Self::BrightBlue => 64,
+            Self::BrightMagenta => 65,
+            Self::BrightCyan => 66,
+            Self::BrightWhite => 67,
+            Self::Fixed(index) => return write!(f, "{};5;{}", base + 8, index),
+            Self::Rgb(red, green, blue) => {
+                return write!(f, "{};2;{};{};{}", base + 8, red, green, blue)
+            }
+        };
+
+        write!(f, "{}", base + index)
+    }
+}
+
+/// The colors and text attributes contents of a GridCell are written with
+///
+/// Styles are written as SGR escape sequences around contents when the grid
+/// is written, so they never count towards the width of a GridCell.
+///
+/// ## Example
+///
+/// ```
+/// use nls_term_grid::{Color, Style};
+///
+/// let style = Style::new().fg(Color::Blue).bold();
+///
+/// assert_eq!(style.paint("dir").to_string(), "\x1b[1;34mdir\x1b[0m");
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Style {
+    /// The color of the text
+    pub foreground: Option<Color>,
+    /// The color of the background
+    pub background: Option<Color>,
+    /// Whether the text is written bold
+    pub bold: bool,
+    /// Whether the text is written dimmed
+    pub dim: bool,
+    /// Whether the text is written in italics
+    pub italic: bool,
+    /// Whether the text is written underlined
+    pub underline: bool,
+    /// Whether the foreground and background colors are swapped
+    pub reverse: bool,
+}
+
+impl Style {
+    /// Create a new Style without colors or text attributes
+    pub const fn new() -> Self {
+        Self {
+            foreground: None,
+            background: None,
+            bold: false,
+            dim: false,
+            italic: false,
+            underline: false,
+            reverse: false,
+        }
+    }
+
+    /// Sets the color of the text
+    pub const fn fg(mut self, color: Color) -> Self {
+        self.foreground = Some(color);
+        self
+    }
+
+    /// Sets the color of the background
+    pub const fn on(mut self, color: Color) -> Self {
+        self.background = Some(color);
+        self
+    }
+
+    /// Sets that the text is written bold
+    pub const fn bold(mut self) -> Self {
+        self.bold = true;
+        self
+    }
+
+    /// Sets that the text is written dimmed
+    pub const fn dim(mut self) -> Self {
+        self.dim = true;
+        self
+    }
+
+    /// Sets that the text is written in italics
+    pub const fn italic(mut self) -> Self {
+        self.italic = true;
+        self
+    }
+
+    /// Sets that the text is written underlined
+    pub const fn underline(mut self) -> Self {
+        self.underline = true;
+        self
+    }
+
+    /// Sets that the foreground and background colors are swapped
+    pub const fn reverse(mut self) -> Self {
+        self.reverse = true;
+        self
+    }
+
+    /// Returns whether the Style has no colors and no text attributes
+    pub fn is_plain(&self) -> bool {
+        *self == Self::new()
+    }
+
+    /// Returns a displayable writing `contents` with the Style,
+    /// regardless of whether colors are enabled
+    pub fn paint<D: fmt::Display>(self, contents: D) -> Painted<D> {
+        Painted {
+            style: self,
+            contents,
+        }
+    }
+
+    /// Writes the SGR escape sequence starting the Style
+    pub(crate) fn write_prefix<F: fmt::Write>(&self, f: &mut F) -> fmt::Result {
+        let attributes = [
+            (self.bold, "1"),
+            (self.dim, "2"),
+            (self.italic, "3"),
+            (self.underline, "4"),
+            (self.reverse, "7"),
+        ];
+        let mut is_first = true;
+        let mut write_seperator = |f: &mut F| {
+            if is_first {
+                is_first = false;
+                Ok(())
+            } else {
+                f.write_char(';')
+            }
+        };
+
+        f.write_str("\x1b[")?;
+        for (_, parameter) in attributes.iter().filter(|(is_set, _)| *is_set) {
+            write_seperator(f)?;
+            f.write_str(parameter)?;
+        }
+        if let Some(foreground) = self.foreground {
+            write_seperator(f)?;
+            foreground.write_parameters(f, 30)?;
+        }
+        if let Some(background) = self.background {
+            write_seperator(f)?;
+            background.write_parameters(f, 40)?;
+        }
+        f.write_char('m')
+    }
+
+    /// Writes `contents` with the Style, wrapped in escape sequences unless the Style is plain
+    pub(crate) fn write_painted<F, D>(&self, f: &mut F, contents: D) -> fmt::Result
+    where
+        F: fmt::Write,
+        D: fmt::Display,
+    {
+        if self.is_plain() {
+            write!(f, "{}", contents)
+        } else {
+            self.write_prefix(f)?;
+            write!(f, "{}", contents)?;
+            f.write_str(RESET)
+        }
+    }
+}
+
+/// The SGR escape sequence resetting every color and text attribute
+const RESET: &str = "\x1b[0m";
+
+/// Contents written with a [`Style`](struct.Style.html), see [`Style::paint`](struct.Style.html#method.paint)
+#[derive(Debug, Clone, Copy)]
+pub struct Painted<D: fmt::Display> {
+    style: Style,
+    contents: D,
+}
+
+impl<D: fmt::Display> fmt::Display for Painted<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.style.write_painted(f, &self.contents)
+    }
+}
@@ -0,0 +1,363 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::{
+    Alignment, Direction, Display, GridCell, GridOptions, GridView, RowFrame, VerticalAlignment,
+};
+
+/// Indicates the characters borders of a [`Table`](struct.Table.html) are drawn with
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum BorderStyle {
+    /// Light box-drawing characters
+    ///
+    /// ## Example
+    ///
+    /// ```text
+    /// ┌──────┬──────┐
+    /// │ name │ size │
+    /// ├──────┼──────┤
+    /// │ file │  100 │
+    /// └──────┴──────┘
+    /// ```
+    Light,
+    /// Heavy box-drawing characters
+    ///
+    /// ## Example
+    ///
+    /// ```text
+    /// ┏━━━━━━┳━━━━━━┓
+    /// ┃ name ┃ size ┃
+    /// ┣━━━━━━╋━━━━━━┫
+    /// ┃ file ┃  100 ┃
+    /// ┗━━━━━━┻━━━━━━┛
+    /// ```
+    Heavy,
+    /// Light box-drawing characters with rounded corners
+    ///
+    /// ## Example
+    ///
+    /// ```text
+    /// ╭──────┬──────╮
+    /// │ name │ size │
+    /// ├──────┼──────┤
+    /// │ file │  100 │
+    /// ╰──────┴──────╯
+    /// ```
+    Rounded,
+    /// Double box-drawing characters
+    ///
+    /// ## Example
+    ///
+    /// ```text
+    /// ╔══════╦══════╗
+    /// ║ name ║ size ║
+    /// ╠══════╬══════╣
+    /// ║ file ║  100 ║
+    /// ╚══════╩══════╝
+    /// ```
+    Double,
+    /// ASCII characters, for output devices without box-drawing characters
+    ///
+    /// ## Example
+    ///
+    /// ```text
+    /// +------+------+
+    /// | name | size |
+    /// +------+------+
+    /// | file |  100 |
+    /// +------+------+
+    /// ```
+    Ascii,
+}
+
+impl Default for BorderStyle {
+    #[inline]
+    fn default() -> Self {
+        Self::Light
+    }
+}
+
+/// The characters of the left end, the junctions and the right end of a horizontal border
+type Rule = [char; 3];
+
+impl BorderStyle {
+    /// Returns the horizontal and the vertical border
+    fn lines(self) -> (char, char) {
+        match self {
+            Self::Light | Self::Rounded => ('─', '│'),
+            Self::Heavy => ('━', '┃'),
+            Self::Double => ('═', '║'),
+            Self::Ascii => ('-', '|'),
+        }
+    }
+
+    /// Returns the top, middle and bottom horizontal borders
+    fn rules(self) -> (Rule, Rule, Rule) {
+        match self {
+            Self::Light => (['┌', '┬', '┐'], ['├', '┼', '┤'], ['└', '┴', '┘']),
+            Self::Rounded => (['╭', '┬', '╮'], ['├', '┼', '┤'], ['╰', '┴', '╯']),
+            Self::Heavy => (['┏', '┳', '┓'], ['┣', '╋', '┫'], ['┗', '┻', '┛']),
+            Self::Double => (['╔', '╦', '╗'], ['╠', '╬', '╣'], ['╚', '╩', '╝']),
+            Self::Ascii => (['+', '+', '+'], ['+', '+', '+'], ['+', '+', '+']),
+        }
+    }
+}
+
+/// A table of GridCells drawn with borders, with an optional header row
+///
+/// Every row must contain the same number of GridCells. The width of each
+/// column is calculated the same way as the columns of a
+/// [`Grid`](struct.Grid.html), including anchored alignment and GridCells
+/// with more than one line.
+///
+/// ## Example
+///
+/// ```
+/// use nls_term_grid::{Alignment, BorderStyle, GridCell, Table};
+///
+/// let table = Table::new()
+///     .with_header([
+///         GridCell::from(String::from("name")),
+///         GridCell::from(String::from("size")),
+///     ])
+///     .with_row([
+///         GridCell::from(String::from("file")),
+///         GridCell::from(String::from("100")),
+///     ])
+///     .with_column_alignments([None, Some(Alignment::Right)])
+///     .with_border_style(BorderStyle::Rounded);
+///
+/// assert_eq!(
+///     table.to_string(),
+///     "╭──────┬──────╮\n\
+///      │ name │ size │\n\
+///      ├──────┼──────┤\n\
+///      │ file │  100 │\n\
+///      ╰──────┴──────╯\n"
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct Table<D: fmt::Display> {
+    /// The GridCells of every row, starting with the header row if there is one
+    cells: Vec<GridCell<D>>,
+    num_columns: usize,
+    has_header: bool,
+    options: GridOptions<'static>,
+    border_style: BorderStyle,
+    inner_padding: usize,
+    outer_padding: usize,
+}
+
+impl<D: fmt::Display> Default for Table<D> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<D: fmt::Display> Table<D> {
+    /// Create a new Table without rows
+    ///
+    /// The border style is [`BorderStyle::Light`](enum.BorderStyle.html#variant.Light),
+    /// with 1 space of inner and outer padding.
+    pub fn new() -> Self {
+        let mut table = Self {
+            cells: Vec::new(),
+            num_columns: 0,
+            has_header: false,
+            options: GridOptions::new(Cow::Borrowed(""), Direction::LeftToRight),
+            border_style: BorderStyle::Light,
+            inner_padding: 1,
+            outer_padding: 1,
+        };
+        table.update_seperator();
+
+        table
+    }
+
+    /// Sets the header row, which is seperated from the other rows by a border
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the header row contains a different number of GridCells from the other rows.
+    pub fn with_header<R>(mut self, header: R) -> Self
+    where
+        R: IntoIterator<Item = GridCell<D>>,
+    {
+        let header: Vec<GridCell<D>> = header.into_iter().collect();
+        if self.has_header {
+            self.cells.drain(..self.num_columns);
+        }
+        self.check_row_len(header.len());
+        self.cells.splice(..0, header);
+        self.has_header = true;
+        self
+    }
+
+    /// Appends a row, see [`push_row`](#method.push_row)
+    pub fn with_row<R>(mut self, row: R) -> Self
+    where
+        R: IntoIterator<Item = GridCell<D>>,
+    {
+        self.push_row(row);
+        self
+    }
+
+    /// Appends a row
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the row contains a different number of GridCells from the other rows.
+    pub fn push_row<R>(&mut self, row: R)
+    where
+        R: IntoIterator<Item = GridCell<D>>,
+    {
+        let row: Vec<GridCell<D>> = row.into_iter().collect();
+        self.check_row_len(row.len());
+        self.cells.extend(row);
+    }
+
+    /// Sets the characters borders are drawn with
+    pub fn with_border_style(mut self, border_style: BorderStyle) -> Self {
+        self.border_style = border_style;
+        self.update_seperator();
+        self
+    }
+
+    /// Sets the number of spaces between contents and the borders between two columns
+    pub fn with_inner_padding(mut self, inner_padding: usize) -> Self {
+        self.inner_padding = inner_padding;
+        self.update_seperator();
+        self
+    }
+
+    /// Sets the number of spaces between contents and the left and right borders of the table
+    pub fn with_outer_padding(mut self, outer_padding: usize) -> Self {
+        self.outer_padding = outer_padding;
+        self
+    }
+
+    /// Overrides the alignment of GridCells in each column, see
+    /// [`Grid::with_column_alignments`](struct.Grid.html#method.with_column_alignments)
+    pub fn with_column_alignments<A>(mut self, column_alignments: A) -> Self
+    where
+        A: Into<Vec<Option<Alignment>>>,
+    {
+        self.options.column_alignments = column_alignments.into();
+        self
+    }
+
+    /// Sets the vertical alignment of GridCells with more than one line, see
+    /// [`Grid::with_vertical_alignment`](struct.Grid.html#method.with_vertical_alignment)
+    pub fn with_vertical_alignment(mut self, vertical_alignment: VerticalAlignment) -> Self {
+        self.options.vertical_alignment = vertical_alignment;
+        self
+    }
+
+    /// Returns the width of each column, excluding padding and borders
+    pub fn column_widths(&self) -> Vec<usize> {
+        if self.num_columns == 0 {
+            return Vec::new();
+        }
+
+        self.display().column_widths().to_vec()
+    }
+
+    /// Checks that a row of `row_len` GridCells can be added to the table
+    fn check_row_len(&mut self, row_len: usize) {
+        if self.cells.is_empty() {
+            self.num_columns = row_len;
+        } else if row_len != self.num_columns {
+            panic!(
+                "row contains {} GridCells, but the table has {} columns",
+                row_len, self.num_columns
+            );
+        }
+    }
+
+    fn update_seperator(&mut self) {
+        let (_, vertical) = self.border_style.lines();
+        let padding = " ".repeat(self.inner_padding);
+        let mut seperator = String::with_capacity(padding.len() * 2 + vertical.len_utf8());
+        seperator.push_str(&padding);
+        seperator.push(vertical);
+        seperator.push_str(&padding);
+
+        self.options.seperator_width = self.inner_padding * 2 + 1;
+        self.options.seperator = Cow::Owned(seperator);
+    }
+
+    fn display(&self) -> Display<'_, D> {
+        GridView {
+            cells: &self.cells,
+            options: &self.options,
+        }
+        .fit_into_columns(self.num_columns)
+    }
+
+    /// Returns a horizontal border drawn with the characters of `rule`
+    fn rule(&self, column_widths: &[usize], rule: Rule) -> String {
+        let (horizontal, _) = self.border_style.lines();
+        let [left, junction, right] = rule;
+        let mut line = String::new();
+
+        line.push(left);
+        for (column_index, column_width) in column_widths.iter().enumerate() {
+            let left_padding = if column_index == 0 {
+                self.outer_padding
+            } else {
+                self.inner_padding
+            };
+            let right_padding = if column_index + 1 == column_widths.len() {
+                self.outer_padding
+            } else {
+                self.inner_padding
+            };
+            if column_index != 0 {
+                line.push(junction);
+            }
+            line.extend(
+                core::iter::repeat(horizontal).take(left_padding + column_width + right_padding),
+            );
+        }
+        line.push(right);
+
+        line
+    }
+}
+
+impl<D: fmt::Display> fmt::Display for Table<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.num_columns == 0 {
+            return Ok(());
+        }
+
+        let display = self.display();
+        let column_widths = display.column_widths();
+        let (top, middle, bottom) = self.border_style.rules();
+        let (_, vertical) = self.border_style.lines();
+
+        let mut left = String::new();
+        left.push(vertical);
+        left.push_str(&" ".repeat(self.outer_padding));
+        let mut right = " ".repeat(self.outer_padding);
+        right.push(vertical);
+        let frame = RowFrame {
+            left: &left,
+            right: &right,
+        };
+
+        writeln!(f, "{}", self.rule(column_widths, top))?;
+        let body_start = if self.has_header {
+            display.write_rows(f, 0..1, Some(&frame))?;
+            writeln!(f, "{}", self.rule(column_widths, middle))?;
+            1
+        } else {
+            0
+        };
+        display.write_rows(f, body_start..display.num_rows(), Some(&frame))?;
+        writeln!(f, "{}", self.rule(column_widths, bottom))
+    }
+}
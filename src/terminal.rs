@@ -0,0 +1,84 @@
+use std::env;
+use std::io::{self, IsTerminal};
+
+/// The display width used when the width of the terminal is unknown
+const DEFAULT_TERMINAL_WIDTH: usize = 80;
+
+/// Returns the width of the terminal in columns, for use as the display
+/// width of [`Grid::fit_into_width`](struct.Grid.html#method.fit_into_width)
+///
+/// The width is the first of the following that is known and greator than 0:
+/// 1. the `COLUMNS` environment variable
+/// 2. the width of the terminal connected to stdout, then stderr, found with
+///    `ioctl(TIOCGWINSZ)` on unix
+/// 3. 80 columns
+///
+/// ## Example
+///
+/// ```no_run
+/// use nls_term_grid::{terminal_width, Direction, Grid, GridCell};
+///
+/// let cells = [GridCell::from(String::from("file1"))];
+/// let grid = Grid::new("  ", Direction::TopToBottom, &cells);
+///
+/// if let Some(display) = grid.fit_into_width(terminal_width()) {
+///     print!("{}", display);
+/// }
+/// ```
+pub fn terminal_width() -> usize {
+    env::var("COLUMNS")
+        .ok()
+        .and_then(|columns| columns.trim().parse::<usize>().ok())
+        .filter(|columns| *columns > 0)
+        .or_else(ioctl_terminal_width)
+        .unwrap_or(DEFAULT_TERMINAL_WIDTH)
+}
+
+/// Returns whether stdout is connected to a terminal
+///
+/// Output which is not written to a terminal, such as output piped to
+/// another program, is usually written one GridCell per line instead of in a grid.
+pub fn stdout_is_tty() -> bool {
+    io::stdout().is_terminal()
+}
+
+/// Returns whether styles should be written to stdout, which is when stdout is
+/// a terminal and the `NO_COLOR` environment variable is not set to a non-empty value
+///
+/// ## Example
+///
+/// ```
+/// use nls_term_grid::{set_colors_enabled, should_color_stdout};
+///
+/// set_colors_enabled(should_color_stdout());
+/// ```
+pub fn should_color_stdout() -> bool {
+    let no_color = env::var_os("NO_COLOR").is_some_and(|no_color| !no_color.is_empty());
+
+    !no_color && stdout_is_tty()
+}
+
+#[cfg(unix)]
+fn ioctl_terminal_width() -> Option<usize> {
+    [libc::STDOUT_FILENO, libc::STDERR_FILENO]
+        .into_iter()
+        .find_map(|fd| {
+            let mut winsize = libc::winsize {
+                ws_row: 0,
+                ws_col: 0,
+                ws_xpixel: 0,
+                ws_ypixel: 0,
+            };
+
+            // SAFETY: TIOCGWINSZ only writes a winsize to the given pointer,
+            // which points to a valid winsize
+            let result = unsafe { libc::ioctl(fd, libc::TIOCGWINSZ, &mut winsize) };
+
+            (result == 0 && winsize.ws_col > 0).then_some(usize::from(winsize.ws_col))
+        })
+}
+
+#[cfg(not(unix))]
+fn ioctl_terminal_width() -> Option<usize> {
+    None
+}
@@ -13,7 +13,11 @@ fn test_gridcell_from_string() {
         GridCell {
             contents: String::from("file"),
             width: 4,
-            alignment: Alignment::Left
+            height: 1,
+            alignment: Alignment::Left,
+            hyperlink: None,
+            style: None,
+            icon: None,
         }
     );
 }
@@ -90,11 +94,11 @@ fn test_fit_into_width_fit_into_one_line() {
 fn test_fit_into_width_fit_into_one_line_color() {
     #[rustfmt::skip]
     let cells: [GridCell; 5] = [
-        GridCell { contents: String::from("\x1b[31mfile1\x1b[0m"), width: 5, alignment: Alignment::Left },
-        GridCell { contents: String::from("\x1b[32mfile2\x1b[0m"), width: 5, alignment: Alignment::Left },
-        GridCell { contents: String::from("\x1b[33mfile3\x1b[0m"), width: 5, alignment: Alignment::Left },
-        GridCell { contents: String::from("\x1b[34mfile4\x1b[0m"), width: 5, alignment: Alignment::Left },
-        GridCell { contents: String::from("\x1b[35mfile5\x1b[0m"), width: 5, alignment: Alignment::Left },
+        GridCell { contents: String::from("\x1b[31mfile1\x1b[0m"), width: 5, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("\x1b[32mfile2\x1b[0m"), width: 5, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("\x1b[33mfile3\x1b[0m"), width: 5, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("\x1b[34mfile4\x1b[0m"), width: 5, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("\x1b[35mfile5\x1b[0m"), width: 5, alignment: Alignment::Left, ..Default::default() },
     ];
 
     let grid = Grid::new("  ", Direction::LeftToRight, &cells);
@@ -153,23 +157,23 @@ fn test_fit_into_width_more_than_one_line_lefttoright() {
 fn test_fit_into_width_more_than_one_line_lefttoright_color() {
     #[rustfmt::skip]
     let cells: [GridCell; 15] = [
-        GridCell { contents: String::from("\x1b[31mfile10\x1b[0m"), width: 6, alignment: Alignment::Left },
-        GridCell { contents: String::from("\x1b[32mfile20\x1b[0m"), width: 6, alignment: Alignment::Left },
-        GridCell { contents: String::from("\x1b[33mfile3\x1b[0m"), width: 5, alignment: Alignment::Left },
-        GridCell { contents: String::from("\x1b[34mfile400\x1b[0m"), width: 7, alignment: Alignment::Left },
-        GridCell { contents: String::from("\x1b[35mfile5\x1b[0m"), width: 5, alignment: Alignment::Left },
-        GridCell { contents: String::from("\x1b[31mfile100\x1b[0m"), width: 7, alignment: Alignment::Left },
-
-        GridCell { contents: String::from("\x1b[32mfile2\x1b[0m"), width: 5, alignment: Alignment::Left },
-        GridCell { contents: String::from("\x1b[33mfile30\x1b[0m"), width: 6, alignment: Alignment::Left },
-        GridCell { contents: String::from("\x1b[34mfile4\x1b[0m"), width: 5, alignment: Alignment::Left },
-        GridCell { contents: String::from("\x1b[35mfile500\x1b[0m"), width: 7, alignment: Alignment::Left },
-
-        GridCell { contents: String::from("\x1b[31mfile1\x1b[0m"), width: 5, alignment: Alignment::Left },
-        GridCell { contents: String::from("\x1b[32mfile200\x1b[0m"), width: 7, alignment: Alignment::Left },
-        GridCell { contents: String::from("\x1b[33mfile300\x1b[0m"), width: 7, alignment: Alignment::Left },
-        GridCell { contents: String::from("\x1b[34mfile40\x1b[0m"), width: 6, alignment: Alignment::Left },
-        GridCell { contents: String::from("\x1b[35mfile50\x1b[0m"), width: 6, alignment: Alignment::Left },
+        GridCell { contents: String::from("\x1b[31mfile10\x1b[0m"), width: 6, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("\x1b[32mfile20\x1b[0m"), width: 6, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("\x1b[33mfile3\x1b[0m"), width: 5, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("\x1b[34mfile400\x1b[0m"), width: 7, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("\x1b[35mfile5\x1b[0m"), width: 5, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("\x1b[31mfile100\x1b[0m"), width: 7, alignment: Alignment::Left, ..Default::default() },
+
+        GridCell { contents: String::from("\x1b[32mfile2\x1b[0m"), width: 5, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("\x1b[33mfile30\x1b[0m"), width: 6, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("\x1b[34mfile4\x1b[0m"), width: 5, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("\x1b[35mfile500\x1b[0m"), width: 7, alignment: Alignment::Left, ..Default::default() },
+
+        GridCell { contents: String::from("\x1b[31mfile1\x1b[0m"), width: 5, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("\x1b[32mfile200\x1b[0m"), width: 7, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("\x1b[33mfile300\x1b[0m"), width: 7, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("\x1b[34mfile40\x1b[0m"), width: 6, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("\x1b[35mfile50\x1b[0m"), width: 6, alignment: Alignment::Left, ..Default::default() },
     ];
 
     let grid = Grid::new("  ", Direction::LeftToRight, &cells);
@@ -229,23 +233,23 @@ fn test_fit_into_width_more_than_one_line_toptobottom() {
 fn test_fit_into_width_more_than_one_line_toptobottom_color() {
     #[rustfmt::skip]
     let cells: [GridCell; 15] = [
-        GridCell { contents: String::from("\x1b[31mfile10\x1b[0m"), width: 6, alignment: Alignment::Left },
-        GridCell { contents: String::from("\x1b[32mfile20\x1b[0m"), width: 6, alignment: Alignment::Left },
-        GridCell { contents: String::from("\x1b[33mfile3\x1b[0m"), width: 5, alignment: Alignment::Left },
-        GridCell { contents: String::from("\x1b[34mfile400\x1b[0m"), width: 7, alignment: Alignment::Left },
-        GridCell { contents: String::from("\x1b[35mfile5\x1b[0m"), width: 5, alignment: Alignment::Left },
-
-        GridCell { contents: String::from("\x1b[31mfile100\x1b[0m"), width: 7, alignment: Alignment::Left },
-        GridCell { contents: String::from("\x1b[32mfile2\x1b[0m"), width: 5, alignment: Alignment::Left },
-        GridCell { contents: String::from("\x1b[33mfile30\x1b[0m"), width: 6, alignment: Alignment::Left },
-        GridCell { contents: String::from("\x1b[34mfile4\x1b[0m"), width: 5, alignment: Alignment::Left },
-        GridCell { contents: String::from("\x1b[35mfile500\x1b[0m"), width: 7, alignment: Alignment::Left },
-
-        GridCell { contents: String::from("\x1b[31mfile1\x1b[0m"), width: 5, alignment: Alignment::Left },
-        GridCell { contents: String::from("\x1b[32mfile200\x1b[0m"), width: 7, alignment: Alignment::Left },
-        GridCell { contents: String::from("\x1b[33mfile300\x1b[0m"), width: 7, alignment: Alignment::Left },
-        GridCell { contents: String::from("\x1b[34mfile40\x1b[0m"), width: 6, alignment: Alignment::Left },
-        GridCell { contents: String::from("\x1b[35mfile50\x1b[0m"), width: 6, alignment: Alignment::Left },
+        GridCell { contents: String::from("\x1b[31mfile10\x1b[0m"), width: 6, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("\x1b[32mfile20\x1b[0m"), width: 6, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("\x1b[33mfile3\x1b[0m"), width: 5, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("\x1b[34mfile400\x1b[0m"), width: 7, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("\x1b[35mfile5\x1b[0m"), width: 5, alignment: Alignment::Left, ..Default::default() },
+
+        GridCell { contents: String::from("\x1b[31mfile100\x1b[0m"), width: 7, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("\x1b[32mfile2\x1b[0m"), width: 5, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("\x1b[33mfile30\x1b[0m"), width: 6, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("\x1b[34mfile4\x1b[0m"), width: 5, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("\x1b[35mfile500\x1b[0m"), width: 7, alignment: Alignment::Left, ..Default::default() },
+
+        GridCell { contents: String::from("\x1b[31mfile1\x1b[0m"), width: 5, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("\x1b[32mfile200\x1b[0m"), width: 7, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("\x1b[33mfile300\x1b[0m"), width: 7, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("\x1b[34mfile40\x1b[0m"), width: 6, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("\x1b[35mfile50\x1b[0m"), width: 6, alignment: Alignment::Left, ..Default::default() },
     ];
 
     let grid = Grid::new("  ", Direction::TopToBottom, &cells);
@@ -266,6 +270,105 @@ fn test_fit_into_width_more_than_one_line_toptobottom_color() {
         );
 }
 
+#[test]
+fn test_display_layout() {
+    #[rustfmt::skip]
+    let cells: [GridCell; 15] = [
+        GridCell::from(String::from("file10")),
+        GridCell::from(String::from("file20")),
+        GridCell::from(String::from("file3")),
+        GridCell::from(String::from("file400")),
+        GridCell::from(String::from("file5")),
+
+        GridCell::from(String::from("file100")),
+        GridCell::from(String::from("file2")),
+        GridCell::from(String::from("file30")),
+        GridCell::from(String::from("file4")),
+        GridCell::from(String::from("file500")),
+
+        GridCell::from(String::from("file1")),
+        GridCell::from(String::from("file200")),
+        GridCell::from(String::from("file300")),
+        GridCell::from(String::from("file40")),
+        GridCell::from(String::from("file50")),
+    ];
+
+    let grid = Grid::new("  ", Direction::TopToBottom, &cells);
+    let display = grid.fit_into_width(35).unwrap();
+
+    assert_eq!(display.num_rows(), 4);
+    assert_eq!(display.num_columns(), 4);
+    assert_eq!(display.column_widths(), &[7, 7, 7, 7]);
+    assert_eq!(display.total_width(), 34);
+
+    let display = grid.fit_into_width(200).unwrap();
+
+    assert_eq!(display.num_rows(), 1);
+    assert_eq!(display.num_columns(), 15);
+    assert_eq!(display.total_width(), 118);
+
+    // 5 GridCells in 4 columns need 2 rows, which leave the last column empty
+    let grid = Grid::new("  ", Direction::TopToBottom, &cells[..5]);
+    let display = grid.fit_into_columns(4);
+    let rendered_width = display.to_string().lines().map(str::len).max();
+
+    assert_eq!(display.num_rows(), 2);
+    assert_eq!(display.num_columns(), 3);
+    assert_eq!(display.column_widths(), &[6, 7, 5]);
+    assert_eq!(display.total_width(), 22);
+    assert_eq!(rendered_width, Some(22));
+    assert_eq!(display.layout().column_widths, [6, 7, 5]);
+
+    let cells: [GridCell; 0] = [];
+    let grid = Grid::new("  ", Direction::TopToBottom, &cells);
+    let display = grid.fit_into_width(80).unwrap();
+
+    assert_eq!(display.num_columns(), 0);
+    assert_eq!(display.total_width(), 0);
+}
+
+#[test]
+fn test_display_positioned_cells() {
+    #[rustfmt::skip]
+    let cells: [GridCell; 5] = [
+        GridCell::from(String::from("file1")),
+        GridCell::from(String::from("file10")),
+        GridCell::from(String::from("file100")),
+        GridCell::from(String::from("file2")),
+        GridCell::from(String::from("file20")),
+    ];
+
+    let grid = Grid::new("  ", Direction::LeftToRight, &cells);
+    let display = grid.fit_into_columns(3);
+
+    #[rustfmt::skip]
+    assert_eq!(
+        display.positioned_cells().collect::<Vec<PositionedCell>>(),
+        [
+            PositionedCell { index: 0, row: 0, column: 0, x: 0, width: 5 },
+            PositionedCell { index: 1, row: 0, column: 1, x: 7, width: 6 },
+            PositionedCell { index: 2, row: 0, column: 2, x: 15, width: 7 },
+            PositionedCell { index: 3, row: 1, column: 0, x: 0, width: 5 },
+            PositionedCell { index: 4, row: 1, column: 1, x: 7, width: 6 },
+        ]
+    );
+
+    let grid = Grid::new("  ", Direction::TopToBottom, &cells);
+    let display = grid.fit_into_columns(3);
+
+    #[rustfmt::skip]
+    assert_eq!(
+        display.positioned_cells().collect::<Vec<PositionedCell>>(),
+        [
+            PositionedCell { index: 0, row: 0, column: 0, x: 0, width: 6 },
+            PositionedCell { index: 2, row: 0, column: 1, x: 8, width: 7 },
+            PositionedCell { index: 4, row: 0, column: 2, x: 17, width: 6 },
+            PositionedCell { index: 1, row: 1, column: 0, x: 0, width: 6 },
+            PositionedCell { index: 3, row: 1, column: 1, x: 8, width: 7 },
+        ]
+    );
+}
+
 #[test]
 fn test_fit_into_columns_lefttoright_same_alignment() {
     #[rustfmt::skip]
@@ -338,23 +441,23 @@ fn test_fit_into_columns_toptobottom_same_alignment() {
 fn test_fit_into_columns_lefttoright_different_alignments() {
     #[rustfmt::skip]
     let cells: [GridCell; 15] = [
-        GridCell { contents: String::from("file10"), width: 6, alignment: Alignment::Left },
-        GridCell { contents: String::from("file20"), width: 6, alignment: Alignment::Right },
-        GridCell { contents: String::from("file3"), width: 5, alignment: Alignment::Left },
-        GridCell { contents: String::from("file400"), width: 7, alignment: Alignment::Right },
-        GridCell { contents: String::from("file5"), width: 5, alignment: Alignment::Left },
-
-        GridCell { contents: String::from("file100"), width: 7, alignment: Alignment::Left },
-        GridCell { contents: String::from("file2"), width: 5, alignment: Alignment::Right },
-        GridCell { contents: String::from("file30"), width: 6, alignment: Alignment::Left },
-        GridCell { contents: String::from("file4"), width: 5, alignment: Alignment::Right },
-        GridCell { contents: String::from("file500"), width: 7, alignment: Alignment::Left },
-
-        GridCell { contents: String::from("file1"), width: 5, alignment: Alignment::Left },
-        GridCell { contents: String::from("file200"), width: 7, alignment: Alignment::Right },
-        GridCell { contents: String::from("file300"), width: 7, alignment: Alignment::Left },
-        GridCell { contents: String::from("file40"), width: 6, alignment: Alignment::Right },
-        GridCell { contents: String::from("file50"), width: 6, alignment: Alignment::Left },
+        GridCell { contents: String::from("file10"), width: 6, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("file20"), width: 6, alignment: Alignment::Right, ..Default::default() },
+        GridCell { contents: String::from("file3"), width: 5, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("file400"), width: 7, alignment: Alignment::Right, ..Default::default() },
+        GridCell { contents: String::from("file5"), width: 5, alignment: Alignment::Left, ..Default::default() },
+
+        GridCell { contents: String::from("file100"), width: 7, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("file2"), width: 5, alignment: Alignment::Right, ..Default::default() },
+        GridCell { contents: String::from("file30"), width: 6, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("file4"), width: 5, alignment: Alignment::Right, ..Default::default() },
+        GridCell { contents: String::from("file500"), width: 7, alignment: Alignment::Left, ..Default::default() },
+
+        GridCell { contents: String::from("file1"), width: 5, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("file200"), width: 7, alignment: Alignment::Right, ..Default::default() },
+        GridCell { contents: String::from("file300"), width: 7, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("file40"), width: 6, alignment: Alignment::Right, ..Default::default() },
+        GridCell { contents: String::from("file50"), width: 6, alignment: Alignment::Left, ..Default::default() },
     ];
 
     let grid = Grid::new("  ", Direction::LeftToRight, &cells);
@@ -369,26 +472,44 @@ fn test_fit_into_columns_lefttoright_different_alignments() {
 }
 
 #[test]
-fn test_fit_into_columns_lefttoright_different_alignments_color() {
+fn test_fit_into_columns_right_alignment_last_column() {
     #[rustfmt::skip]
-    let cells: [GridCell; 15] = [
-        GridCell { contents: String::from("\x1b[31mfile10\x1b[0m"), width: 6, alignment: Alignment::Left },
-        GridCell { contents: String::from("\x1b[32mfile20\x1b[0m"), width: 6, alignment: Alignment::Right },
-        GridCell { contents: String::from("\x1b[33mfile3\x1b[0m"), width: 5, alignment: Alignment::Left },
-        GridCell { contents: String::from("\x1b[34mfile400\x1b[0m"), width: 7, alignment: Alignment::Right },
-        GridCell { contents: String::from("\x1b[35mfile5\x1b[0m"), width: 5, alignment: Alignment::Left },
+    let cells: [GridCell; 4] = [
+        GridCell::from(String::from("a")),
+        GridCell { alignment: Alignment::Right, ..GridCell::from(String::from("bbb")) },
+        GridCell::from(String::from("cc")),
+        GridCell { alignment: Alignment::Right, ..GridCell::from(String::from("d")) },
+    ];
 
-        GridCell { contents: String::from("\x1b[31mfile100\x1b[0m"), width: 7, alignment: Alignment::Left },
-        GridCell { contents: String::from("\x1b[32mfile2\x1b[0m"), width: 5, alignment: Alignment::Right },
-        GridCell { contents: String::from("\x1b[33mfile30\x1b[0m"), width: 6, alignment: Alignment::Left },
-        GridCell { contents: String::from("\x1b[34mfile4\x1b[0m"), width: 5, alignment: Alignment::Right },
-        GridCell { contents: String::from("\x1b[35mfile500\x1b[0m"), width: 7, alignment: Alignment::Left },
+    // right aligned GridCells in the last column are written with padding and a seperator
+    let grid = Grid::new("  ", Direction::LeftToRight, &cells);
+    assert_eq!(
+        grid.fit_into_columns(2).to_string(),
+        "a   bbb  \ncc    d  \n"
+    );
+}
 
-        GridCell { contents: String::from("\x1b[31mfile1\x1b[0m"), width: 5, alignment: Alignment::Left },
-        GridCell { contents: String::from("\x1b[32mfile200\x1b[0m"), width: 7, alignment: Alignment::Right },
-        GridCell { contents: String::from("\x1b[33mfile300\x1b[0m"), width: 7, alignment: Alignment::Left },
-        GridCell { contents: String::from("\x1b[34mfile40\x1b[0m"), width: 6, alignment: Alignment::Right },
-        GridCell { contents: String::from("\x1b[35mfile50\x1b[0m"), width: 6, alignment: Alignment::Left },
+#[test]
+fn test_fit_into_columns_lefttoright_different_alignments_color() {
+    #[rustfmt::skip]
+    let cells: [GridCell; 15] = [
+        GridCell { contents: String::from("\x1b[31mfile10\x1b[0m"), width: 6, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("\x1b[32mfile20\x1b[0m"), width: 6, alignment: Alignment::Right, ..Default::default() },
+        GridCell { contents: String::from("\x1b[33mfile3\x1b[0m"), width: 5, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("\x1b[34mfile400\x1b[0m"), width: 7, alignment: Alignment::Right, ..Default::default() },
+        GridCell { contents: String::from("\x1b[35mfile5\x1b[0m"), width: 5, alignment: Alignment::Left, ..Default::default() },
+
+        GridCell { contents: String::from("\x1b[31mfile100\x1b[0m"), width: 7, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("\x1b[32mfile2\x1b[0m"), width: 5, alignment: Alignment::Right, ..Default::default() },
+        GridCell { contents: String::from("\x1b[33mfile30\x1b[0m"), width: 6, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("\x1b[34mfile4\x1b[0m"), width: 5, alignment: Alignment::Right, ..Default::default() },
+        GridCell { contents: String::from("\x1b[35mfile500\x1b[0m"), width: 7, alignment: Alignment::Left, ..Default::default() },
+
+        GridCell { contents: String::from("\x1b[31mfile1\x1b[0m"), width: 5, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("\x1b[32mfile200\x1b[0m"), width: 7, alignment: Alignment::Right, ..Default::default() },
+        GridCell { contents: String::from("\x1b[33mfile300\x1b[0m"), width: 7, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("\x1b[34mfile40\x1b[0m"), width: 6, alignment: Alignment::Right, ..Default::default() },
+        GridCell { contents: String::from("\x1b[35mfile50\x1b[0m"), width: 6, alignment: Alignment::Left, ..Default::default() },
     ];
 
     let grid = Grid::new("  ", Direction::LeftToRight, &cells);
@@ -411,23 +532,23 @@ fn test_fit_into_columns_lefttoright_different_alignments_color() {
 fn test_fit_into_columns_toptobottom_different_alignments() {
     #[rustfmt::skip]
     let cells: [GridCell; 15] = [
-        GridCell { contents: String::from("file10"), width: 6, alignment: Alignment::Left },
-        GridCell { contents: String::from("file20"), width: 6, alignment: Alignment::Left },
-        GridCell { contents: String::from("file3"), width: 5, alignment: Alignment::Left },
-        GridCell { contents: String::from("file400"), width: 7, alignment: Alignment::Right },
-        GridCell { contents: String::from("file5"), width: 5, alignment: Alignment::Right },
-
-        GridCell { contents: String::from("file100"), width: 7, alignment: Alignment::Right },
-        GridCell { contents: String::from("file2"), width: 5, alignment: Alignment::Left },
-        GridCell { contents: String::from("file30"), width: 6, alignment: Alignment::Left },
-        GridCell { contents: String::from("file4"), width: 5, alignment: Alignment::Left },
-        GridCell { contents: String::from("file500"), width: 7, alignment: Alignment::Right },
-
-        GridCell { contents: String::from("file1"), width: 5, alignment: Alignment::Right },
-        GridCell { contents: String::from("file200"), width: 7, alignment: Alignment::Right },
-        GridCell { contents: String::from("file300"), width: 7, alignment: Alignment::Left },
-        GridCell { contents: String::from("file40"), width: 6, alignment: Alignment::Left },
-        GridCell { contents: String::from("file50"), width: 6, alignment: Alignment::Left },
+        GridCell { contents: String::from("file10"), width: 6, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("file20"), width: 6, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("file3"), width: 5, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("file400"), width: 7, alignment: Alignment::Right, ..Default::default() },
+        GridCell { contents: String::from("file5"), width: 5, alignment: Alignment::Right, ..Default::default() },
+
+        GridCell { contents: String::from("file100"), width: 7, alignment: Alignment::Right, ..Default::default() },
+        GridCell { contents: String::from("file2"), width: 5, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("file30"), width: 6, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("file4"), width: 5, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("file500"), width: 7, alignment: Alignment::Right, ..Default::default() },
+
+        GridCell { contents: String::from("file1"), width: 5, alignment: Alignment::Right, ..Default::default() },
+        GridCell { contents: String::from("file200"), width: 7, alignment: Alignment::Right, ..Default::default() },
+        GridCell { contents: String::from("file300"), width: 7, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("file40"), width: 6, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("file50"), width: 6, alignment: Alignment::Left, ..Default::default() },
     ];
 
     let grid = Grid::new("  ", Direction::TopToBottom, &cells);
@@ -445,23 +566,23 @@ fn test_fit_into_columns_toptobottom_different_alignments() {
 fn test_fit_into_columns_toptobottom_different_alignments_color() {
     #[rustfmt::skip]
     let cells: [GridCell; 15] = [
-        GridCell { contents: String::from("\x1b[31mfile10\x1b[0m"), width: 6, alignment: Alignment::Left },
-        GridCell { contents: String::from("\x1b[32mfile20\x1b[0m"), width: 6, alignment: Alignment::Left },
-        GridCell { contents: String::from("\x1b[33mfile3\x1b[0m"), width: 5, alignment: Alignment::Left },
-        GridCell { contents: String::from("\x1b[34mfile400\x1b[0m"), width: 7, alignment: Alignment::Right },
-        GridCell { contents: String::from("\x1b[35mfile5\x1b[0m"), width: 5, alignment: Alignment::Right },
-
-        GridCell { contents: String::from("\x1b[31mfile100\x1b[0m"), width: 7, alignment: Alignment::Right },
-        GridCell { contents: String::from("\x1b[32mfile2\x1b[0m"), width: 5, alignment: Alignment::Left },
-        GridCell { contents: String::from("\x1b[33mfile30\x1b[0m"), width: 6, alignment: Alignment::Left },
-        GridCell { contents: String::from("\x1b[34mfile4\x1b[0m"), width: 5, alignment: Alignment::Left },
-        GridCell { contents: String::from("\x1b[35mfile500\x1b[0m"), width: 7, alignment: Alignment::Right },
-
-        GridCell { contents: String::from("\x1b[31mfile1\x1b[0m"), width: 5, alignment: Alignment::Right },
-        GridCell { contents: String::from("\x1b[32mfile200\x1b[0m"), width: 7, alignment: Alignment::Right },
-        GridCell { contents: String::from("\x1b[33mfile300\x1b[0m"), width: 7, alignment: Alignment::Left },
-        GridCell { contents: String::from("\x1b[34mfile40\x1b[0m"), width: 6, alignment: Alignment::Left },
-        GridCell { contents: String::from("\x1b[35mfile50\x1b[0m"), width: 6, alignment: Alignment::Left },
+        GridCell { contents: String::from("\x1b[31mfile10\x1b[0m"), width: 6, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("\x1b[32mfile20\x1b[0m"), width: 6, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("\x1b[33mfile3\x1b[0m"), width: 5, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("\x1b[34mfile400\x1b[0m"), width: 7, alignment: Alignment::Right, ..Default::default() },
+        GridCell { contents: String::from("\x1b[35mfile5\x1b[0m"), width: 5, alignment: Alignment::Right, ..Default::default() },
+
+        GridCell { contents: String::from("\x1b[31mfile100\x1b[0m"), width: 7, alignment: Alignment::Right, ..Default::default() },
+        GridCell { contents: String::from("\x1b[32mfile2\x1b[0m"), width: 5, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("\x1b[33mfile30\x1b[0m"), width: 6, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("\x1b[34mfile4\x1b[0m"), width: 5, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("\x1b[35mfile500\x1b[0m"), width: 7, alignment: Alignment::Right, ..Default::default() },
+
+        GridCell { contents: String::from("\x1b[31mfile1\x1b[0m"), width: 5, alignment: Alignment::Right, ..Default::default() },
+        GridCell { contents: String::from("\x1b[32mfile200\x1b[0m"), width: 7, alignment: Alignment::Right, ..Default::default() },
+        GridCell { contents: String::from("\x1b[33mfile300\x1b[0m"), width: 7, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("\x1b[34mfile40\x1b[0m"), width: 6, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("\x1b[35mfile50\x1b[0m"), width: 6, alignment: Alignment::Left, ..Default::default() },
     ];
 
     let grid = Grid::new("  ", Direction::TopToBottom, &cells);
@@ -479,3 +600,1394 @@ fn test_fit_into_columns_toptobottom_different_alignments_color() {
              \x1b[33mfile3\x1b[0m   \x1b[31mfile100\x1b[0m  \x1b[34mfile4\x1b[0m   \x1b[32mfile200\x1b[0m  \x1b[35mfile50\x1b[0m\n"
         );
 }
+
+#[test]
+fn test_ansi_width() {
+    assert_eq!(ansi_width("file"), 4);
+    assert_eq!(ansi_width("\x1b[31mfile\x1b[0m"), 4);
+    assert_eq!(ansi_width("\x1b[38;5;208m文件\x1b[0m"), 4);
+    assert_eq!(
+        ansi_width("\x1b]8;;file:///tmp/file\x1b\\file\x1b]8;;\x1b\\"),
+        4
+    );
+    assert_eq!(ansi_width("\x1b]0;title\x07file"), 4);
+    assert_eq!(ansi_width("\x1bPpayload\x1b\\file"), 4);
+    assert_eq!(ansi_width("\x1b(Bfile"), 4);
+    assert_eq!(ansi_width("file\x1b"), 4);
+    assert_eq!(ansi_width("file\x1b[31"), 4);
+}
+
+#[test]
+fn test_gridcell_with_anchor() {
+    let cell = GridCell::from(String::from("1.5K")).with_anchor('.');
+    assert_eq!(cell.alignment, Alignment::Anchored(1));
+
+    let cell = GridCell::from(String::from("100")).with_anchor('.');
+    assert_eq!(cell.alignment, Alignment::Anchored(3));
+
+    let cell = GridCell::from_ansi_string(String::from("\x1b[1;32m12.25M\x1b[0m")).with_anchor('M');
+    assert_eq!(cell.alignment, Alignment::Anchored(5));
+}
+
+#[test]
+fn test_gridcell_from_ansi_string() {
+    assert_eq!(
+        GridCell::from_ansi_string(String::from("\x1b[1;34mdir\x1b[0m")),
+        GridCell {
+            contents: String::from("\x1b[1;34mdir\x1b[0m"),
+            width: 3,
+            height: 1,
+            alignment: Alignment::Left,
+            hyperlink: None,
+            style: None,
+            icon: None,
+        }
+    );
+}
+
+#[test]
+fn test_fit_into_width_from_ansi_string() {
+    let cells: Vec<GridCell> = [
+        "file10", "file20", "file3", "file400", "file5", "file100", "file2", "file30", "file4",
+        "file500", "file1", "file200", "file300", "file40", "file50",
+    ]
+    .iter()
+    .map(|name| GridCell::from_ansi_string(format!("\x1b[31m{}\x1b[0m", name)))
+    .collect();
+
+    let grid = Grid::new("  ", Direction::TopToBottom, &cells);
+    let display = grid.fit_into_width(35).unwrap();
+
+    assert_eq!(
+        display.to_string(),
+        "\x1b[31mfile10\x1b[0m   \x1b[31mfile5\x1b[0m    \x1b[31mfile4\x1b[0m    \x1b[31mfile300\x1b[0m\n\
+         \x1b[31mfile20\x1b[0m   \x1b[31mfile100\x1b[0m  \x1b[31mfile500\x1b[0m  \x1b[31mfile40\x1b[0m\n\
+         \x1b[31mfile3\x1b[0m    \x1b[31mfile2\x1b[0m    \x1b[31mfile1\x1b[0m    \x1b[31mfile50\x1b[0m\n\
+         \x1b[31mfile400\x1b[0m  \x1b[31mfile30\x1b[0m   \x1b[31mfile200\x1b[0m\n"
+    );
+}
+
+#[test]
+fn test_gridcell_with_hyperlink() {
+    assert_eq!(
+        GridCell::from(String::from("file")).with_hyperlink("file:///tmp/file"),
+        GridCell {
+            contents: String::from("file"),
+            width: 4,
+            height: 1,
+            alignment: Alignment::Left,
+            hyperlink: Some(String::from("file:///tmp/file")),
+            style: None,
+            icon: None,
+        }
+    );
+}
+
+#[test]
+fn test_fit_into_columns_hyperlink() {
+    #[rustfmt::skip]
+    let cells: [GridCell; 4] = [
+        GridCell::from(String::from("file100")).with_hyperlink("file:///file100"),
+        GridCell::from(String::from("file1")).with_hyperlink("file:///file1"),
+        GridCell { contents: String::from("file2"), width: 5, height: 1, alignment: Alignment::Right, hyperlink: Some(String::from("file:///file2")), style: None, icon: None },
+        GridCell::from(String::from("file20")).with_hyperlink("file:///file20"),
+    ];
+
+    let grid = Grid::new("  ", Direction::LeftToRight, &cells);
+    let display = grid.fit_into_columns(2);
+
+    // if evaluated in a output device which renders OSC 8 hyperlinks
+    // the following will be rendered with only the file names being clickable:
+    // "file100  file1\n\
+    //    file2  file20\n"
+    assert_eq!(
+        display.to_string(),
+        "\x1b]8;;file:///file100\x1b\\file100\x1b]8;;\x1b\\  \x1b]8;;file:///file1\x1b\\file1\x1b]8;;\x1b\\\n\
+         \x20\x20\x1b]8;;file:///file2\x1b\\file2\x1b]8;;\x1b\\  \x1b]8;;file:///file20\x1b\\file20\x1b]8;;\x1b\\\n"
+    );
+}
+
+#[test]
+fn test_fit_into_columns_center_alignment() {
+    #[rustfmt::skip]
+    let cells: [GridCell; 6] = [
+        GridCell { contents: String::from("name"), width: 4, alignment: Alignment::Center, ..Default::default() },
+        GridCell { contents: String::from("size"), width: 4, alignment: Alignment::Center, ..Default::default() },
+        GridCell { contents: String::from("file100"), width: 7, alignment: Alignment::Center, ..Default::default() },
+        GridCell { contents: String::from("1"), width: 1, alignment: Alignment::Center, ..Default::default() },
+        GridCell { contents: String::from("file2"), width: 5, alignment: Alignment::Center, ..Default::default() },
+        GridCell { contents: String::from("1024"), width: 4, alignment: Alignment::Center, ..Default::default() },
+    ];
+
+    let grid = Grid::new("  ", Direction::LeftToRight, &cells);
+    let display = grid.fit_into_columns(2);
+
+    // odd padding puts the extra space on the right side, and the last column
+    // is written without trailing padding
+    assert_eq!(
+        display.to_string(),
+        "\x20name    size\n\
+         file100   1\n\
+         \x20file2   1024\n"
+    );
+}
+
+#[test]
+fn test_fit_into_columns_anchored_alignment() {
+    let cells: Vec<GridCell> = [
+        ("file1", "1.5K"),
+        ("file20", "12.25M"),
+        ("file300", "100"),
+        ("file4", "3.0G"),
+    ]
+    .iter()
+    .flat_map(|(name, size)| {
+        [
+            GridCell::from(String::from(*size)).with_anchor('.'),
+            GridCell::from(String::from(*name)),
+        ]
+    })
+    .collect();
+
+    let grid = Grid::new("  ", Direction::LeftToRight, &cells);
+    let display = grid.fit_into_columns(2);
+
+    assert_eq!(
+        display.to_string(),
+        "\x20\x201.5K   file1\n\
+         \x2012.25M  file20\n\
+         100      file300\n\
+         \x20\x203.0G   file4\n"
+    );
+}
+
+#[test]
+fn test_fit_into_columns_column_alignments() {
+    #[rustfmt::skip]
+    let cells: [GridCell; 9] = [
+        GridCell::from(String::from("file10")),
+        GridCell::from(String::from("3")),
+        GridCell::from(String::from("a")),
+        GridCell::from(String::from("file2")),
+        GridCell { contents: String::from("200"), width: 3, alignment: Alignment::Left, ..Default::default() },
+        GridCell { contents: String::from("bbb"), width: 3, alignment: Alignment::Right, ..Default::default() },
+        GridCell::from(String::from("file300")),
+        GridCell::from(String::from("10")),
+        GridCell::from(String::from("cc")),
+    ];
+
+    let grid = Grid::new("  ", Direction::LeftToRight, &cells).with_column_alignments([
+        None,
+        Some(Alignment::Right),
+        Some(Alignment::Center),
+    ]);
+    let display = grid.fit_into_columns(3);
+
+    assert_eq!(
+        display.to_string(),
+        "file10     3   a\n\
+         file2    200  bbb\n\
+         file300   10  cc\n"
+    );
+
+    let grid = Grid::new("  ", Direction::TopToBottom, &cells)
+        .with_column_alignments([Some(Alignment::Right)]);
+    let display = grid.fit_into_columns(3);
+
+    assert_eq!(
+        display.to_string(),
+        "file10  file2  file300\n\
+         \x20\x20\x20\x20\x203  200    10\n\
+         \x20\x20\x20\x20\x20a    bbb  cc\n"
+    );
+}
+
+/// A xorshift generator, to generate the same GridCells on every run
+struct Xorshift(u32);
+
+impl Xorshift {
+    /// Returns a number less than `bound`
+    fn next(&mut self, bound: u32) -> usize {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 17;
+        self.0 ^= self.0 << 5;
+        (self.0 % bound) as usize
+    }
+
+    /// Returns fewer than `max_cell_count` GridCells of random widths
+    fn cells(&mut self, max_cell_count: u32) -> Vec<GridCell> {
+        let cell_count = self.next(max_cell_count);
+        let max_width = 1 + self.next(30);
+
+        (0..cell_count)
+            .map(|_| GridCell::from("x".repeat(self.next(max_width as u32) + 1)))
+            .collect()
+    }
+}
+
+/// The layout search of nls_term_grid v0.3.0, which calculates the dimentions
+/// of every candidate number of columns
+fn reference_fit_into_width(
+    grid: &Grid<'_, '_, String>,
+    display_width: usize,
+) -> Option<Dimentions> {
+    let grid = grid.view();
+    if grid.cells.is_empty() {
+        return Some(Dimentions::one_row(Vec::new()));
+    }
+    let max_cell_width: usize = grid.cells.iter().map(|cell| cell.width).max().unwrap_or(0);
+    if max_cell_width >= display_width {
+        return None;
+    }
+    let total_width: usize = grid.cells.iter().map(|cell| cell.width).sum::<usize>()
+        + (grid.total_cell_count() - 1) * grid.options.seperator_width;
+    if total_width <= display_width {
+        return Some(Dimentions::one_row(
+            grid.cells.iter().map(|cell| cell.width).collect(),
+        ));
+    }
+
+    let mut num_columns = (display_width / (max_cell_width + grid.options.seperator_width)).max(1);
+    let mut dimentions = grid.calculate_dimentions(num_columns, usize::MAX);
+    loop {
+        num_columns += 1;
+        let new_dimentions = grid.calculate_dimentions(num_columns, usize::MAX);
+
+        if new_dimentions.total_width(grid.options.seperator_width) > display_width {
+            break;
+        } else if Dimentions::is_well_packed(
+            num_columns,
+            new_dimentions.num_rows,
+            grid.total_cell_count(),
+            dimentions.num_rows,
+        ) {
+            dimentions = new_dimentions;
+        }
+    }
+
+    Some(dimentions)
+}
+
+#[test]
+fn test_fit_into_width_same_as_reference() {
+    let mut rng = Xorshift(0x2545_f491);
+
+    for _ in 0..300 {
+        let cells = rng.cells(150);
+        let seperator = " ".repeat(rng.next(4));
+        let display_width = 5 + rng.next(150);
+
+        for direction in [Direction::LeftToRight, Direction::TopToBottom] {
+            let grid = Grid::new(&*seperator, direction, &cells);
+            let expected = reference_fit_into_width(&grid, display_width);
+            let display = grid.fit_into_width(display_width);
+
+            assert_eq!(
+                display
+                    .as_ref()
+                    .map(|display| &display.dimentions.column_widths),
+                expected
+                    .as_ref()
+                    .map(|dimentions| &dimentions.column_widths),
+                "{} cells, {:?}, display width {}",
+                cells.len(),
+                direction,
+                display_width
+            );
+            assert_eq!(
+                display.map(|display| display.dimentions.num_rows),
+                expected.map(|dimentions| dimentions.num_rows)
+            );
+        }
+    }
+}
+
+type GnuLsOutputs = &'static [(usize, &'static str, &'static str)];
+
+/// Expected output of GNU coreutils 9.1 `ls`, generated in an empty directory
+/// containing files with the given names by running
+/// `LC_ALL=C ls -C -T0 -w WIDTH` and `LC_ALL=C ls -x -T0 -w WIDTH`
+///
+/// Each entry is (file names sorted by `ls`, [(WIDTH, `ls -C` output, `ls -x` output)])
+#[rustfmt::skip]
+const GNU_LS_CORPUS: &[(&[&str], GnuLsOutputs)] = &[
+    (
+        &["file1", "file10", "file100", "file2", "file20", "file200", "file3", "file30", "file300", "file4", "file40", "file400", "file5", "file50", "file500"],
+        &[
+            (20, "file1    file300\nfile10   file4\nfile100  file40\nfile2    file400\nfile20   file5\nfile200  file50\nfile3    file500\nfile30\n", "file1    file10\nfile100  file2\nfile20   file200\nfile3    file30\nfile300  file4\nfile40   file400\nfile5    file50\nfile500\n"),
+            (30, "file1    file200  file40\nfile10   file3    file400\nfile100  file30   file5\nfile2    file300  file50\nfile20   file4    file500\n", "file1  file10  file100\nfile2  file20  file200\nfile3  file30  file300\nfile4  file40  file400\nfile5  file50  file500\n"),
+            (41, "file1    file20   file300  file5\nfile10   file200  file4    file50\nfile100  file3    file40   file500\nfile2    file30   file400\n", "file1    file10   file100  file2\nfile20   file200  file3    file30\nfile300  file4    file40   file400\nfile5    file50   file500\n"),
+            (50, "file1    file2    file3    file4    file5\nfile10   file20   file30   file40   file50\nfile100  file200  file300  file400  file500\n", "file1  file10  file100  file2  file20  file200\nfile3  file30  file300  file4  file40  file400\nfile5  file50  file500\n"),
+            (60, "file1    file2    file3    file4    file5\nfile10   file20   file30   file40   file50\nfile100  file200  file300  file400  file500\n", "file1    file10   file100  file2   file20   file200  file3\nfile30   file300  file4    file40  file400  file5    file50\nfile500\n"),
+            (80, "file1   file100  file20   file3   file300  file40   file5   file500\nfile10  file2    file200  file30  file4    file400  file50\n", "file1  file10  file100  file2  file20  file200  file3  file30  file300\nfile4  file40  file400  file5  file50  file500\n"),
+        ],
+    ),
+    (
+        &["CHANGELOG.md", "Cargo.lock", "Cargo.toml", "LICENSE-APACHE", "LICENSE-MIT", "README.md", "benches", "build.rs", "clippy.toml", "deny.toml", "examples", "rustfmt.toml", "src", "target", "tests"],
+        &[
+            (20, "CHANGELOG.md\nCargo.lock\nCargo.toml\nLICENSE-APACHE\nLICENSE-MIT\nREADME.md\nbenches\nbuild.rs\nclippy.toml\ndeny.toml\nexamples\nrustfmt.toml\nsrc\ntarget\ntests\n", "CHANGELOG.md\nCargo.lock\nCargo.toml\nLICENSE-APACHE\nLICENSE-MIT\nREADME.md\nbenches\nbuild.rs\nclippy.toml\ndeny.toml\nexamples\nrustfmt.toml\nsrc\ntarget\ntests\n"),
+            (30, "CHANGELOG.md    clippy.toml\nCargo.lock      deny.toml\nCargo.toml      examples\nLICENSE-APACHE  rustfmt.toml\nLICENSE-MIT     src\nREADME.md       target\nbenches         tests\nbuild.rs\n", "CHANGELOG.md  Cargo.lock\nCargo.toml    LICENSE-APACHE\nLICENSE-MIT   README.md\nbenches       build.rs\nclippy.toml   deny.toml\nexamples      rustfmt.toml\nsrc           target\ntests\n"),
+            (41, "CHANGELOG.md    clippy.toml\nCargo.lock      deny.toml\nCargo.toml      examples\nLICENSE-APACHE  rustfmt.toml\nLICENSE-MIT     src\nREADME.md       target\nbenches         tests\nbuild.rs\n", "CHANGELOG.md  Cargo.lock\nCargo.toml    LICENSE-APACHE\nLICENSE-MIT   README.md\nbenches       build.rs\nclippy.toml   deny.toml\nexamples      rustfmt.toml\nsrc           target\ntests\n"),
+            (50, "CHANGELOG.md    LICENSE-MIT  clippy.toml   src\nCargo.lock      README.md    deny.toml     target\nCargo.toml      benches      examples      tests\nLICENSE-APACHE  build.rs     rustfmt.toml\n", "CHANGELOG.md    Cargo.lock   Cargo.toml\nLICENSE-APACHE  LICENSE-MIT  README.md\nbenches         build.rs     clippy.toml\ndeny.toml       examples     rustfmt.toml\nsrc             target       tests\n"),
+            (60, "CHANGELOG.md    LICENSE-MIT  clippy.toml   src\nCargo.lock      README.md    deny.toml     target\nCargo.toml      benches      examples      tests\nLICENSE-APACHE  build.rs     rustfmt.toml\n", "CHANGELOG.md  Cargo.lock  Cargo.toml  LICENSE-APACHE\nLICENSE-MIT   README.md   benches     build.rs\nclippy.toml   deny.toml   examples    rustfmt.toml\nsrc           target      tests\n"),
+            (80, "CHANGELOG.md  LICENSE-APACHE  benches      deny.toml     src\nCargo.lock    LICENSE-MIT     build.rs     examples      target\nCargo.toml    README.md       clippy.toml  rustfmt.toml  tests\n", "CHANGELOG.md  Cargo.lock    Cargo.toml  LICENSE-APACHE  LICENSE-MIT\nREADME.md     benches       build.rs    clippy.toml     deny.toml\nexamples      rustfmt.toml  src         target          tests\n"),
+        ],
+    ),
+    (
+        &["a", "bb", "ccc", "dddd", "eeeee", "f", "gg", "hhh", "iiii", "jjjjj", "k", "ll", "mmm", "nnnn", "ooooo", "p", "qq", "rrr", "ssss", "this_is_a_very_long_file_name.txt"],
+        &[
+            (20, "a\nbb\nccc\ndddd\neeeee\nf\ngg\nhhh\niiii\njjjjj\nk\nll\nmmm\nnnnn\nooooo\np\nqq\nrrr\nssss\nthis_is_a_very_long_file_name.txt\n", "a\nbb\nccc\ndddd\neeeee\nf\ngg\nhhh\niiii\njjjjj\nk\nll\nmmm\nnnnn\nooooo\np\nqq\nrrr\nssss\nthis_is_a_very_long_file_name.txt\n"),
+            (30, "a\nbb\nccc\ndddd\neeeee\nf\ngg\nhhh\niiii\njjjjj\nk\nll\nmmm\nnnnn\nooooo\np\nqq\nrrr\nssss\nthis_is_a_very_long_file_name.txt\n", "a\nbb\nccc\ndddd\neeeee\nf\ngg\nhhh\niiii\njjjjj\nk\nll\nmmm\nnnnn\nooooo\np\nqq\nrrr\nssss\nthis_is_a_very_long_file_name.txt\n"),
+            (41, "a      k\nbb     ll\nccc    mmm\ndddd   nnnn\neeeee  ooooo\nf      p\ngg     qq\nhhh    rrr\niiii   ssss\njjjjj  this_is_a_very_long_file_name.txt\n", "a      bb\nccc    dddd\neeeee  f\ngg     hhh\niiii   jjjjj\nk      ll\nmmm    nnnn\nooooo  p\nqq     rrr\nssss   this_is_a_very_long_file_name.txt\n"),
+            (50, "a      hhh    ooooo\nbb     iiii   p\nccc    jjjjj  qq\ndddd   k      rrr\neeeee  ll     ssss\nf      mmm    this_is_a_very_long_file_name.txt\ngg     nnnn\n", "a      bb                                 ccc\ndddd   eeeee                              f\ngg     hhh                                iiii\njjjjj  k                                  ll\nmmm    nnnn                               ooooo\np      qq                                 rrr\nssss   this_is_a_very_long_file_name.txt\n"),
+            (60, "a      f      k      p\nbb     gg     ll     qq\nccc    hhh    mmm    rrr\ndddd   iiii   nnnn   ssss\neeeee  jjjjj  ooooo  this_is_a_very_long_file_name.txt\n", "a  bb  ccc  dddd  eeeee\nf  gg  hhh  iiii  jjjjj\nk  ll  mmm  nnnn  ooooo\np  qq  rrr  ssss  this_is_a_very_long_file_name.txt\n"),
+            (80, "a    dddd   gg    jjjjj  mmm    p    ssss\nbb   eeeee  hhh   k      nnnn   qq   this_is_a_very_long_file_name.txt\nccc  f      iiii  ll     ooooo  rrr\n", "a  bb  ccc  dddd  eeeee  f  gg  hhh  iiii  jjjjj\nk  ll  mmm  nnnn  ooooo  p  qq  rrr  ssss  this_is_a_very_long_file_name.txt\n"),
+        ],
+    ),
+    (
+        &["1", "10", "11", "12", "13", "14", "15", "16", "17", "18", "19", "2", "20", "21", "22", "23", "24", "25", "26", "27", "28", "29", "3", "30", "31", "32", "33", "34", "35", "36", "37", "38", "39", "4", "40", "5", "6", "7", "8", "9"],
+        &[
+            (20, "1   17  24  31  39\n10  18  25  32  4\n11  19  26  33  40\n12  2   27  34  5\n13  20  28  35  6\n14  21  29  36  7\n15  22  3   37  8\n16  23  30  38  9\n", "1   10  11  12  13\n14  15  16  17  18\n19  2   20  21  22\n23  24  25  26  27\n28  29  3   30  31\n32  33  34  35  36\n37  38  39  4   40\n5   6   7   8   9\n"),
+            (30, "1   15  20  26  31  37  6\n10  16  21  27  32  38  7\n11  17  22  28  33  39  8\n12  18  23  29  34  4   9\n13  19  24  3   35  40\n14  2   25  30  36  5\n", "1   10  11  12  13  14  15\n16  17  18  19  2   20  21\n22  23  24  25  26  27  28\n29  3   30  31  32  33  34\n35  36  37  38  39  4   40\n5   6   7   8   9\n"),
+            (41, "1   13  17  20  24  28  31  35  39  6\n10  14  18  21  25  29  32  36  4   7\n11  15  19  22  26  3   33  37  40  8\n12  16  2   23  27  30  34  38  5   9\n", "1   10  11  12  13  14  15  16  17  18\n19  2   20  21  22  23  24  25  26  27\n28  29  3   30  31  32  33  34  35  36\n37  38  39  4   40  5   6   7   8   9\n"),
+            (50, "1   13  17  20  24  28  31  35  39  6\n10  14  18  21  25  29  32  36  4   7\n11  15  19  22  26  3   33  37  40  8\n12  16  2   23  27  30  34  38  5   9\n", "1   10  11  12  13  14  15  16  17  18  19  2\n20  21  22  23  24  25  26  27  28  29  3   30\n31  32  33  34  35  36  37  38  39  4   40  5\n6   7   8   9\n"),
+            (60, "1   12  15  18  20  23  26  29  31  34  37  4   6  9\n10  13  16  19  21  24  27  3   32  35  38  40  7\n11  14  17  2   22  25  28  30  33  36  39  5   8\n", "1   10  11  12  13  14  15  16  17  18  19  2   20  21  22\n23  24  25  26  27  28  29  3   30  31  32  33  34  35  36\n37  38  39  4   40  5   6   7   8   9\n"),
+            (80, "1   11  13  15  17  19  20  22  24  26  28  3   31  33  35  37  39  40  6  8\n10  12  14  16  18  2   21  23  25  27  29  30  32  34  36  38  4   5   7  9\n", "1   10  11  12  13  14  15  16  17  18  19  2   20  21  22  23  24  25  26  27\n28  29  3   30  31  32  33  34  35  36  37  38  39  4   40  5   6   7   8   9\n"),
+        ],
+    ),
+];
+
+#[test]
+fn test_fit_into_width_gnu_layout_strategy() {
+    for (names, outputs) in GNU_LS_CORPUS {
+        let cells: Vec<GridCell> = names
+            .iter()
+            .map(|name| GridCell::from(String::from(*name)))
+            .collect();
+
+        for (display_width, ls_c_output, ls_x_output) in outputs.iter() {
+            // a GridCell at least as wide as the display width does not fit into a grid,
+            // where `ls` falls back to writing one file name per line
+            if names.iter().any(|name| name.len() >= *display_width) {
+                let one_per_line: String = names.iter().map(|name| format!("{}\n", name)).collect();
+                assert_eq!(*ls_c_output, one_per_line);
+                assert_eq!(*ls_x_output, one_per_line);
+
+                let grid = Grid::new("  ", Direction::TopToBottom, &cells)
+                    .with_layout_strategy(LayoutStrategy::Gnu);
+                assert!(grid.fit_into_width(*display_width).is_none());
+                continue;
+            }
+
+            let grid = Grid::new("  ", Direction::TopToBottom, &cells)
+                .with_layout_strategy(LayoutStrategy::Gnu);
+            let display = grid.fit_into_width(*display_width).unwrap();
+
+            assert_eq!(
+                display.to_string(),
+                *ls_c_output,
+                "ls -C -w {}",
+                display_width
+            );
+
+            let grid = Grid::new("  ", Direction::LeftToRight, &cells)
+                .with_layout_strategy(LayoutStrategy::Gnu);
+            let display = grid.fit_into_width(*display_width).unwrap();
+
+            assert_eq!(
+                display.to_string(),
+                *ls_x_output,
+                "ls -x -w {}",
+                display_width
+            );
+        }
+    }
+}
+
+#[test]
+fn test_gridcell_truncate() {
+    let end = Truncation::default();
+    let middle = Truncation {
+        position: TruncationPosition::Middle,
+        ellipsis: "...".into(),
+    };
+
+    let mut cell = GridCell::from(String::from("file"));
+    assert!(!cell.truncate(4, &end));
+    assert_eq!(cell.contents, "file");
+
+    let mut cell = GridCell::from(String::from("a_very_long_file_name"));
+    assert!(cell.truncate(10, &end));
+    assert_eq!(cell.contents, "a_very_lo…");
+    assert_eq!(cell.width, 10);
+
+    let mut cell = GridCell::from(String::from("a_very_long_file_name"));
+    assert!(cell.truncate(10, &middle));
+    assert_eq!(cell.contents, "a_ve...ame");
+    assert_eq!(cell.width, 10);
+
+    // wide characters are not split, so the width can be less than max_width
+    let mut cell = GridCell::from(String::from("文件文件文件"));
+    assert!(cell.truncate(6, &end));
+    assert_eq!(cell.contents, "文件…");
+    assert_eq!(cell.width, 5);
+
+    // combining characters stay with the character they combine with
+    let mut cell = GridCell::from(String::from("cafe\u{301}_menu"));
+    assert!(cell.truncate(5, &end));
+    assert_eq!(cell.contents, "cafe\u{301}…");
+    assert_eq!(cell.width, 5);
+
+    // escape sequences are kept
+    let mut cell = GridCell::from_ansi_string(String::from("\x1b[31ma_very_long_file_name\x1b[0m"));
+    assert!(cell.truncate(10, &middle));
+    assert_eq!(cell.contents, "\x1b[31ma_ve...ame\x1b[0m");
+    assert_eq!(cell.width, 10);
+
+    // the ellipsis is dropped if it does not fit
+    let mut cell = GridCell::from(String::from("file"));
+    assert!(cell.truncate(2, &middle));
+    assert_eq!(cell.contents, "fe");
+    assert_eq!(cell.width, 2);
+}
+
+#[test]
+fn test_fit_into_width_overflow_truncate() {
+    #[rustfmt::skip]
+    let cells: [GridCell; 5] = [
+        GridCell::from(String::from("file1")),
+        GridCell::from(String::from("file2")),
+        GridCell::from(String::from("a_very_long_file_name")).with_hyperlink("file:///a_very_long_file_name"),
+        GridCell::from(String::from("file3")),
+        GridCell::from(String::from("file4")),
+    ];
+
+    let grid = Grid::new("  ", Direction::TopToBottom, &cells);
+
+    assert!(grid.fit_into_width(16).is_none());
+
+    let grid = Grid::new("  ", Direction::TopToBottom, &cells).with_overflow(Overflow::Truncate(
+        Truncation {
+            position: TruncationPosition::Middle,
+            ellipsis: "~".into(),
+        },
+    ));
+    let display = grid.fit_into_width(16).unwrap();
+
+    assert_eq!(display.column_widths(), &[15]);
+    assert_eq!(
+        display.to_string(),
+        "file1\n\
+         file2\n\
+         \x1b]8;;file:///a_very_long_file_name\x1b\\a_very_~le_name\x1b]8;;\x1b\\\n\
+         file3\n\
+         file4\n"
+    );
+
+    let display = grid.fit_into_width(40).unwrap();
+
+    assert_eq!(
+        display.to_string(),
+        "file1  \x1b]8;;file:///a_very_long_file_name\x1b\\a_very_long_file_name\x1b]8;;\x1b\\  file4\n\
+         file2  file3\n"
+    );
+}
+
+#[test]
+fn test_gridcell_from_string_multi_line() {
+    assert_eq!(
+        GridCell::from(String::from("file\nsubline")),
+        GridCell {
+            contents: String::from("file\nsubline"),
+            width: 7,
+            height: 2,
+            alignment: Alignment::Left,
+            hyperlink: None,
+            style: None,
+            icon: None,
+        }
+    );
+
+    let cell = GridCell::from_ansi_string(String::from("\x1b[34mdir\x1b[0m\n4 KiB"));
+    assert_eq!((cell.width, cell.height), (5, 2));
+}
+
+#[test]
+fn test_fit_into_columns_multi_line() {
+    #[rustfmt::skip]
+    let cells: [GridCell; 5] = [
+        GridCell::from(String::from("file1")),
+        GridCell::from(String::from("file2\n4 KiB\n2024-01-01")),
+        GridCell { contents: String::from("file3\n12 B"), width: 5, height: 2, alignment: Alignment::Right, ..Default::default() },
+        GridCell::from(String::from("file4")),
+        GridCell::from(String::from("file5")),
+    ];
+
+    let grid = Grid::new("  ", Direction::LeftToRight, &cells);
+    let display = grid.fit_into_columns(3);
+
+    assert_eq!(
+        display.to_string(),
+        "file1  file2       file3\n\
+         \x20      4 KiB        12 B\n\
+         \x20      2024-01-01\n\
+         file4  file5\n"
+    );
+
+    let grid = Grid::new("  ", Direction::LeftToRight, &cells)
+        .with_vertical_alignment(VerticalAlignment::Bottom);
+    let display = grid.fit_into_columns(3);
+
+    assert_eq!(
+        display.to_string(),
+        "\x20      file2\n\
+         \x20      4 KiB       file3\n\
+         file1  2024-01-01   12 B\n\
+         file4  file5\n"
+    );
+
+    let grid = Grid::new("  ", Direction::TopToBottom, &cells)
+        .with_vertical_alignment(VerticalAlignment::Middle);
+    let display = grid.fit_into_columns(2);
+
+    assert_eq!(
+        display.to_string(),
+        "file1       file4\n\
+         file2\n\
+         4 KiB       file5\n\
+         2024-01-01\n\
+         \x20    file3\n\
+         \x20     12 B\n"
+    );
+}
+
+#[test]
+fn test_fit_into_width_overflow_truncate_multi_line() {
+    #[rustfmt::skip]
+    let cells: [GridCell; 2] = [
+        GridCell::from(String::from("file1")),
+        GridCell::from(String::from("a_very_long_file_name\n4 KiB")),
+    ];
+
+    let grid = Grid::new("  ", Direction::TopToBottom, &cells)
+        .with_overflow(Overflow::Truncate(Truncation::default()));
+    let display = grid.fit_into_width(12).unwrap();
+
+    assert_eq!(display.to_string(), "file1\na_very_lon…\n4 KiB\n");
+}
+
+#[test]
+fn test_fit_into_columns_truncate_wide_characters() {
+    #[rustfmt::skip]
+    let cells: [GridCell; 4] = [
+        GridCell::from(String::from("中中中")),
+        GridCell::from(String::from("ab")),
+        GridCell::from(String::from("cd")),
+        GridCell::from(String::from("ef")),
+    ];
+
+    let grid = Grid::new("|", Direction::LeftToRight, &cells).with_max_column_width(4);
+    let display = grid.fit_into_columns(2);
+
+    assert_eq!(display.column_widths(), &[4, 2]);
+    assert_eq!(display.to_string(), "中… |ab\ncd  |ef\n");
+
+    #[rustfmt::skip]
+    let cells: [GridCell; 4] = [
+        GridCell::from(String::from("中中中")),
+        GridCell::from(String::from("ab\ncd")),
+        GridCell::from(String::from("中中中\nefg")),
+        GridCell::from(String::from("hi")),
+    ];
+
+    let grid = Grid::new("|", Direction::LeftToRight, &cells).with_max_column_width(4);
+    let display = grid.fit_into_columns(2);
+
+    assert_eq!(display.to_string(), "中… |ab\n    |cd\n中… |hi\nefg\n");
+}
+
+#[test]
+fn test_owned_grid_same_as_grid() {
+    let names = [
+        "file10", "file20", "file3", "file400", "file5", "file100", "file2", "file30",
+    ];
+    let cells: Vec<GridCell> = names
+        .iter()
+        .map(|name| GridCell::from(String::from(*name)))
+        .collect();
+
+    for direction in [Direction::LeftToRight, Direction::TopToBottom] {
+        let grid = Grid::new("  ", direction, &cells);
+        let mut owned_grid: OwnedGrid<String> = cells[..3]
+            .iter()
+            .cloned()
+            .collect::<OwnedGrid<String>>()
+            .with_seperator("  ")
+            .with_direction(direction);
+        owned_grid.extend(cells[3..].iter().cloned());
+
+        assert_eq!(owned_grid.cells(), &cells[..]);
+        for display_width in [10, 20, 30, 80] {
+            assert_eq!(
+                owned_grid
+                    .fit_into_width(display_width)
+                    .unwrap()
+                    .to_string(),
+                grid.fit_into_width(display_width).unwrap().to_string()
+            );
+        }
+        assert_eq!(
+            owned_grid.fit_into_columns(3).to_string(),
+            grid.fit_into_columns(3).to_string()
+        );
+    }
+}
+
+#[test]
+fn test_streaming_grid_same_as_fit_into_width() {
+    let mut rng = Xorshift(0x1f12_3bb5);
+
+    for _ in 0..60 {
+        let cells = rng.cells(120);
+        let seperator = " ".repeat(rng.next(4));
+        let display_width = 5 + rng.next(150);
+        let overflow = if rng.next(2) == 0 {
+            Overflow::Fail
+        } else {
+            Overflow::Truncate(Truncation::default())
+        };
+        let is_constrained = rng.next(2) == 0;
+        let max_columns = 1 + rng.next(12);
+        let min_column_width = rng.next(8);
+        let max_column_width = 3 + rng.next(30);
+
+        for direction in [Direction::LeftToRight, Direction::TopToBottom] {
+            let mut streaming_grid = StreamingGrid::new(display_width)
+                .with_seperator(seperator.clone())
+                .with_direction(direction)
+                .with_overflow(overflow.clone());
+            if is_constrained {
+                streaming_grid = streaming_grid
+                    .with_max_columns(max_columns)
+                    .with_min_column_width(min_column_width)
+                    .with_max_column_width(max_column_width);
+            }
+            let mut previous_layout: Option<(usize, Vec<usize>)> = Some((1, Vec::new()));
+
+            for (cell_count, cell) in cells.iter().enumerate().map(|(i, cell)| (i + 1, cell)) {
+                let is_changed = streaming_grid.push(cell.clone());
+
+                let mut grid = Grid::new(&*seperator, direction, &cells[..cell_count])
+                    .with_overflow(overflow.clone());
+                if is_constrained {
+                    grid = grid
+                        .with_max_columns(max_columns)
+                        .with_min_column_width(min_column_width)
+                        .with_max_column_width(max_column_width);
+                }
+                let expected = grid.fit_into_width(display_width);
+                let display = streaming_grid.display();
+                assert_eq!(
+                    display.as_ref().map(|display| display.to_string()),
+                    expected.as_ref().map(|display| display.to_string()),
+                    "{} cells, {:?}, display width {}",
+                    cell_count,
+                    direction,
+                    display_width
+                );
+
+                let layout =
+                    expected.map(|display| (display.num_rows(), display.column_widths().to_vec()));
+                assert_eq!(is_changed, layout != previous_layout);
+                previous_layout = layout;
+            }
+        }
+    }
+}
+
+#[test]
+fn test_fit_into_rows() {
+    let cells: Vec<GridCell> = [
+        "file10", "file20", "file3", "file400", "file5", "file100", "file2",
+    ]
+    .iter()
+    .map(|name| GridCell::from(String::from(*name)))
+    .collect();
+
+    let grid = Grid::new("  ", Direction::TopToBottom, &cells);
+    let display = grid.fit_into_rows(3);
+
+    assert_eq!((display.num_rows(), display.num_columns()), (3, 3));
+    assert_eq!(
+        display.to_string(),
+        "file10  file400  file2\n\
+         file20  file5\n\
+         file3   file100\n"
+    );
+
+    // 7 cells into 5 rows leaves the last 3 rows with one cell
+    let display = grid.fit_into_rows(5);
+    assert_eq!((display.num_rows(), display.num_columns()), (5, 2));
+    assert_eq!(
+        display.to_string(),
+        "file10   file100\n\
+         file20   file2\n\
+         file3\n\
+         file400\n\
+         file5\n"
+    );
+
+    let grid = Grid::new("  ", Direction::LeftToRight, &cells);
+    let display = grid.fit_into_rows(5);
+
+    // left to right fills every row except the last, so only 4 rows are used
+    assert_eq!((display.num_rows(), display.num_columns()), (4, 2));
+    assert_eq!(
+        display.to_string(),
+        "file10  file20\n\
+         file3   file400\n\
+         file5   file100\n\
+         file2\n"
+    );
+
+    assert_eq!(
+        grid.fit_into_rows(0).to_string(),
+        grid.fit_into_rows(1).to_string()
+    );
+    assert_eq!(grid.fit_into_rows(100).num_rows(), 7);
+
+    assert!(grid.fit_into_rows_within_width(2, 31).is_some());
+    assert!(grid.fit_into_rows_within_width(2, 30).is_none());
+
+    let cells: [GridCell; 0] = [];
+    let grid = Grid::new("  ", Direction::TopToBottom, &cells);
+    assert_eq!(grid.fit_into_rows(3).to_string(), "\n");
+}
+
+#[test]
+fn test_fit_into_width_column_constraints() {
+    let cells: Vec<GridCell> = [
+        "file10",
+        "file20",
+        "file3",
+        "file400",
+        "file5",
+        "a_very_long_file_name",
+        "file2",
+    ]
+    .iter()
+    .map(|name| GridCell::from(String::from(*name)))
+    .collect();
+
+    let grid = Grid::new("  ", Direction::LeftToRight, &cells).with_max_columns(3);
+    let display = grid.fit_into_width(200).unwrap();
+
+    assert_eq!(display.num_columns(), 3);
+    assert_eq!(
+        display.to_string(),
+        "file10   file20  file3\n\
+         file400  file5   a_very_long_file_name\n\
+         file2\n"
+    );
+
+    let grid = Grid::new("  ", Direction::TopToBottom, &cells)
+        .with_max_column_width(10)
+        .with_min_column_width(8);
+    let display = grid.fit_into_width(40).unwrap();
+
+    assert_eq!(display.column_widths(), &[8, 8, 10, 8]);
+    assert_eq!(
+        display.to_string(),
+        "file10    file3     file5       file2\n\
+         file20    file400   a_very_lo…\n"
+    );
+
+    // the GridCells are too wide without truncation
+    let grid = Grid::new("  ", Direction::TopToBottom, &cells);
+    assert!(grid.fit_into_width(20).is_none());
+    let grid = grid.with_max_column_width(19);
+    assert!(grid.fit_into_width(20).is_some());
+
+    let grid = Grid::new("  ", Direction::LeftToRight, &cells)
+        .with_max_columns(2)
+        .with_layout_strategy(LayoutStrategy::Gnu);
+    assert_eq!(grid.fit_into_width(200).unwrap().num_columns(), 2);
+}
+
+#[test]
+fn test_table() {
+    let rows = [["file1", "1.5K"], ["file200", "12.25M"], ["file30", "100"]];
+    let mut table: Table<String> = Table::new()
+        .with_header([
+            GridCell::from(String::from("name")),
+            GridCell::from(String::from("size")),
+        ])
+        .with_border_style(BorderStyle::Ascii)
+        .with_inner_padding(2)
+        .with_outer_padding(0);
+    for [name, size] in rows {
+        table.push_row([
+            GridCell::from(String::from(name)),
+            GridCell::from(String::from(size)).with_anchor('.'),
+        ]);
+    }
+
+    assert_eq!(table.column_widths(), [7, 7]);
+    assert_eq!(
+        table.to_string(),
+        "+---------+---------+\n\
+         |name     |  size   |\n\
+         +---------+---------+\n\
+         |file1    |    1.5K |\n\
+         |file200  |   12.25M|\n\
+         |file30   |  100    |\n\
+         +---------+---------+\n"
+    );
+
+    let table: Table<String> = Table::new()
+        .with_row([
+            GridCell::from(String::from("file1")),
+            GridCell::from(String::from("4 KiB\n2024-01-01")),
+        ])
+        .with_border_style(BorderStyle::Double)
+        .with_vertical_alignment(VerticalAlignment::Bottom);
+
+    assert_eq!(
+        table.to_string(),
+        "╔═══════╦════════════╗\n\
+         ║       ║ 4 KiB      ║\n\
+         ║ file1 ║ 2024-01-01 ║\n\
+         ╚═══════╩════════════╝\n"
+    );
+
+    let table: Table<String> = Table::new();
+    assert_eq!(table.to_string(), "");
+}
+
+#[test]
+#[should_panic]
+fn test_table_row_len_mismatch() {
+    let _ = Table::new()
+        .with_header([
+            GridCell::from(String::from("name")),
+            GridCell::from(String::from("size")),
+        ])
+        .with_row([GridCell::from(String::from("file1"))]);
+}
+
+#[test]
+fn test_table_empty() {
+    let table: Table<String> = Table::new().with_border_style(BorderStyle::Ascii);
+
+    assert!(table.column_widths().is_empty());
+    assert_eq!(table.to_string(), "");
+}
+
+#[test]
+fn test_long_listing() {
+    // output of GNU coreutils 9.1 `ls -l --time-style=long-iso`
+    let rows = [
+        [
+            "-rw-r--r--",
+            "1",
+            "root",
+            "root",
+            "0",
+            "2026-10-18 07:24",
+            "a",
+        ],
+        [
+            "-rw-r--r--",
+            "1",
+            "root",
+            "root",
+            "0",
+            "2026-10-18 07:24",
+            "bbbbbbbbbb",
+        ],
+        [
+            "-rw-r--r--",
+            "1",
+            "root",
+            "root",
+            "5000",
+            "2026-10-18 07:24",
+            "big",
+        ],
+        [
+            "drwxr-xr-x",
+            "2",
+            "root",
+            "root",
+            "4096",
+            "2026-10-18 07:24",
+            "d",
+        ],
+    ];
+    let mut listing: LongListing<String> = LongListing::new().with_total(12);
+    for row in rows {
+        listing.push_row(row.iter().map(|field| GridCell::from(String::from(*field))));
+    }
+
+    assert_eq!(listing.column_widths(), [10, 1, 4, 4, 4, 16, 10]);
+    assert_eq!(
+        listing.to_string(),
+        "total 12\n\
+         -rw-r--r-- 1 root root    0 2026-10-18 07:24 a\n\
+         -rw-r--r-- 1 root root    0 2026-10-18 07:24 bbbbbbbbbb\n\
+         -rw-r--r-- 1 root root 5000 2026-10-18 07:24 big\n\
+         drwxr-xr-x 2 root root 4096 2026-10-18 07:24 d\n"
+    );
+
+    // device numbers are lined up on the comma
+    let listing: LongListing<String> = LongListing::new()
+        .with_column_alignments([])
+        .with_row([
+            GridCell::from(String::from("sda")),
+            GridCell::from(String::from("8, 0")).with_anchor(','),
+        ])
+        .with_row([
+            GridCell::from(String::from("sda10")),
+            GridCell::from(String::from("8, 10")).with_anchor(','),
+        ])
+        .with_row([
+            GridCell::from(String::from("nvme0n1")),
+            GridCell::from(String::from("259, 0")).with_anchor(','),
+        ]);
+
+    assert_eq!(
+        listing.to_string(),
+        "sda       8, 0\n\
+         sda10     8, 10\n\
+         nvme0n1 259, 0\n"
+    );
+
+    assert_eq!(
+        LongListing::<String>::new().with_total(0).to_string(),
+        "total 0\n"
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_terminal_width_columns() {
+    std::env::set_var("COLUMNS", "123");
+    assert_eq!(crate::terminal_width(), 123);
+
+    // invalid values of COLUMNS are ignored
+    std::env::set_var("COLUMNS", "0");
+    assert_ne!(crate::terminal_width(), 0);
+    std::env::set_var("COLUMNS", "wide");
+    assert!(crate::terminal_width() > 0);
+
+    std::env::remove_var("COLUMNS");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_display_write_to() {
+    fn assert_write_to_eq_to_string(display: Display<'_, String>) {
+        let mut output: Vec<u8> = Vec::new();
+        display.write_to(&mut output).unwrap();
+        assert_eq!(String::from_utf8(output).unwrap(), display.to_string());
+    }
+
+    let cells: Vec<GridCell> = (0..100)
+        .map(|i| GridCell::from(format!("file{}", i * i * 7)))
+        .collect();
+    for direction in [Direction::LeftToRight, Direction::TopToBottom] {
+        let grid = Grid::new("  ", direction, &cells);
+        for display_width in [1, 20, 80, 200, 1000] {
+            if let Some(display) = grid.fit_into_width(display_width) {
+                assert_write_to_eq_to_string(display);
+            }
+        }
+    }
+
+    // padding wider than the static padding buffer
+    let cells = [
+        GridCell::from(String::from("a")),
+        GridCell::from("b".repeat(150)),
+        GridCell::from(String::from("c")),
+        GridCell::from(String::from("d\ne")),
+    ];
+    let grid = Grid::new(" ", Direction::TopToBottom, &cells);
+    assert_write_to_eq_to_string(grid.fit_into_columns(2));
+
+    // truncated GridCells with more than one line
+    let grid = Grid::new(" ", Direction::LeftToRight, &cells)
+        .with_vertical_alignment(VerticalAlignment::Middle)
+        .with_overflow(Overflow::Truncate(Truncation::default()));
+    assert_write_to_eq_to_string(grid.fit_into_width(40).unwrap());
+
+    assert_write_to_eq_to_string(Grid::new(" ", Direction::LeftToRight, &[]).fit_into_columns(1));
+}
+
+#[test]
+fn test_display_with_layout() {
+    let cells: Vec<GridCell> = (0..30)
+        .map(|i| GridCell::from(format!("file{}", i * i)))
+        .collect();
+    for direction in [Direction::LeftToRight, Direction::TopToBottom] {
+        let grid = Grid::new("  ", direction, &cells)
+            .with_overflow(Overflow::Truncate(Truncation::default()));
+        for display_width in [5, 30, 80] {
+            let display = grid.fit_into_width(display_width).unwrap();
+            let layout = display.layout();
+            assert_eq!(layout.num_rows, display.num_rows());
+            assert_eq!(layout.column_widths, display.column_widths());
+            assert_eq!(
+                grid.display_with_layout(layout).to_string(),
+                display.to_string()
+            );
+        }
+    }
+
+    assert_eq!(
+        Grid::new("  ", Direction::LeftToRight, &cells[..2])
+            .fit_into_width(80)
+            .unwrap()
+            .layout(),
+        Layout {
+            num_rows: 1,
+            column_widths: vec![5, 5],
+            anchor_widths: vec![0, 0],
+            truncation_width: None,
+        }
+    );
+}
+
+#[test]
+fn test_display_with_layout_not_computed_for_cells() {
+    let cells: Vec<GridCell> = (1..=5).map(|i| GridCell::from(format!("f{}", i))).collect();
+    let grid = Grid::new(" ", Direction::TopToBottom, &cells);
+
+    let display = grid.display_with_layout(Layout {
+        num_rows: usize::MAX,
+        column_widths: vec![2, 2, 2],
+        anchor_widths: vec![],
+        truncation_width: None,
+    });
+    assert_eq!(display.num_rows(), 5);
+    assert_eq!(display.to_string(), "f1\nf2\nf3\nf4\nf5\n");
+
+    let grid = Grid::new(" ", Direction::LeftToRight, &cells);
+    let display = grid.display_with_layout(Layout {
+        num_rows: usize::MAX,
+        column_widths: vec![2, 2, 2],
+        anchor_widths: vec![],
+        truncation_width: None,
+    });
+    assert_eq!(display.to_string(), "f1 f2 f3\nf4 f5\n");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_layout_round_trip() {
+    let cells = [
+        GridCell::from(String::from("file1")),
+        GridCell::from(String::from("file10")).with_hyperlink("file:///file10"),
+        GridCell::from(String::from("8, 1")).with_anchor(','),
+        GridCell::from(String::from("a very long file name")),
+    ];
+    let grid = Grid::new("  ", Direction::TopToBottom, &cells)
+        .with_overflow(Overflow::Truncate(Truncation::default()));
+    let display = grid.fit_into_width(15).unwrap();
+
+    // the GridCells and the layout are computed by one process and rendered by another
+    let cells_json = serde_json::to_string(&cells).unwrap();
+    let layout_json = serde_json::to_string(&display.layout()).unwrap();
+
+    let client_cells: Vec<GridCell> = serde_json::from_str(&cells_json).unwrap();
+    let client_layout: Layout = serde_json::from_str(&layout_json).unwrap();
+    assert_eq!(client_cells, cells);
+    assert_eq!(client_layout, display.layout());
+
+    let client_grid = Grid::new("  ", Direction::TopToBottom, &client_cells)
+        .with_overflow(Overflow::Truncate(Truncation::default()));
+    assert_eq!(
+        client_grid.display_with_layout(client_layout).to_string(),
+        display.to_string()
+    );
+
+    let alignment: Alignment = serde_json::from_str(r#"{"Anchored":2}"#).unwrap();
+    assert_eq!(alignment, Alignment::Anchored(2));
+}
+
+#[test]
+fn test_display_html() {
+    let cells = [
+        GridCell::from(String::from("a&b")).with_hyperlink("file:///a&b"),
+        GridCell::from_ansi_string(String::from("\x1b[31m\"c\"\x1b[0m")),
+        GridCell::from(String::from("8, 1")).with_anchor(','),
+        GridCell::from(String::from("259, 10")).with_anchor(','),
+        GridCell::from(String::from("d\ne")),
+    ];
+
+    // rows and columns are the same as when written to a terminal
+    let grid = Grid::new("  ", Direction::TopToBottom, &cells);
+    assert_eq!(
+        grid.fit_into_columns(3).html().to_string(),
+        "<table style=\"white-space: pre\">\n\
+         <tr style=\"vertical-align: top\"><td><a href=\"file:///a&amp;b\">a&amp;b</a></td>\
+         <td>  8, 1</td><td>d\ne</td></tr>\n\
+         <tr><td>&quot;c&quot;</td><td>259, 10</td></tr>\n\
+         </table>\n"
+    );
+
+    let grid = Grid::new("  ", Direction::LeftToRight, &cells[..2])
+        .with_column_alignments([Some(Alignment::Center)]);
+    assert_eq!(
+        grid.fit_into_columns(1).html().to_string(),
+        "<table style=\"white-space: pre\">\n\
+         <tr><td style=\"text-align: center\"><a href=\"file:///a&amp;b\">a&amp;b</a></td></tr>\n\
+         <tr><td style=\"text-align: center\">&quot;c&quot;</td></tr>\n\
+         </table>\n"
+    );
+}
+
+#[test]
+fn test_gridcell_style() {
+    assert_eq!(Style::new().paint("a").to_string(), "a");
+    assert_eq!(
+        Style::new()
+            .fg(Color::BrightRed)
+            .on(Color::Fixed(236))
+            .dim()
+            .italic()
+            .underline()
+            .reverse()
+            .paint("a")
+            .to_string(),
+        "\x1b[2;3;4;7;91;48;5;236ma\x1b[0m"
+    );
+    assert_eq!(
+        Style::new()
+            .fg(Color::Rgb(255, 128, 0))
+            .on(Color::Black)
+            .paint("a")
+            .to_string(),
+        "\x1b[38;2;255;128;0;40ma\x1b[0m"
+    );
+
+    let blue = Style::new().fg(Color::Blue).bold();
+    let cells = [
+        GridCell::from(String::from("dir")).with_style(blue),
+        GridCell::from(String::from("file")),
+        GridCell::from(String::from("long_dir")).with_style(blue),
+        GridCell::from(String::from("d\ne")).with_style(blue),
+    ];
+    let grid = Grid::new("  ", Direction::LeftToRight, &cells);
+
+    // styles do not count towards the width and padding is written outside of styles
+    let display = grid.fit_into_columns(2);
+    assert_eq!(display.column_widths(), &[8, 4]);
+    assert_eq!(
+        display.to_string(),
+        "\x1b[1;34mdir\x1b[0m       file\n\
+         \x1b[1;34mlong_dir\x1b[0m  \x1b[1;34md\x1b[0m\n          \
+         \x1b[1;34me\x1b[0m\n"
+    );
+
+    let grid = grid.with_overflow(Overflow::Truncate(Truncation::default()));
+    assert_eq!(
+        grid.fit_into_width(6).unwrap().to_string(),
+        "\x1b[1;34mdir\x1b[0m\nfile\n\x1b[1;34mlong…\x1b[0m\n\x1b[1;34md\x1b[0m\n\x1b[1;34me\x1b[0m\n"
+    );
+}
+
+#[cfg(feature = "ls_colors")]
+#[test]
+fn test_ls_colors() {
+    // the styles of GNU ls when LS_COLORS is not set
+    let defaults = LsColors::new();
+    assert_eq!(
+        defaults.style_for("bin", FileType::Directory),
+        Some(Style::new().bold().fg(Color::Blue))
+    );
+    assert_eq!(
+        defaults.style_for("run.sh", FileType::Executable),
+        Some(Style::new().bold().fg(Color::Green))
+    );
+    assert_eq!(defaults.style_for("a.tar", FileType::RegularFile), None);
+    assert_eq!(defaults.style_for("a", FileType::MultipleHardLinks), None);
+    assert_eq!(
+        defaults.style_for("link", FileType::OrphanSymlink),
+        defaults.style_for("link", FileType::Symlink)
+    );
+
+    let ls_colors = LsColors::parse(
+        "rs=0:di=01;34:ln=01;36:mh=00:pi=40;33:or=40;31;01:mi=00:ex=00:tw=30;42:ow=:\
+         fi=38;5;250:*.tar=01;31:*.tar.gz=38;2;255;0;128:*README=04:*.bad=01;x:zz=01",
+    );
+    assert_eq!(
+        ls_colors.style_for("fifo", FileType::Fifo),
+        Some(Style::new().on(Color::Black).fg(Color::Yellow))
+    );
+    assert_eq!(
+        ls_colors.style_for("link", FileType::OrphanSymlink),
+        Some(Style::new().on(Color::Black).fg(Color::Red).bold())
+    );
+    assert_eq!(ls_colors.style_for("gone", FileType::MissingFile), None);
+
+    // suffixes are matched ignoring case and later keys take precedence
+    assert_eq!(
+        ls_colors.style_for("x.TAR", FileType::RegularFile),
+        Some(Style::new().bold().fg(Color::Red))
+    );
+    assert_eq!(
+        ls_colors.style_for("x.tar.gz", FileType::RegularFile),
+        Some(Style::new().fg(Color::Rgb(255, 0, 128)))
+    );
+    assert_eq!(
+        ls_colors.style_for("README", FileType::RegularFile),
+        Some(Style::new().underline())
+    );
+    assert_eq!(
+        ls_colors.style_for("x.bad", FileType::RegularFile),
+        Some(Style::new().fg(Color::Fixed(250)))
+    );
+
+    // types without a style are styled as regular files or directories
+    assert_eq!(
+        ls_colors.style_for("x.tar", FileType::Executable),
+        Some(Style::new().bold().fg(Color::Red))
+    );
+    assert_eq!(
+        ls_colors.style_for("tmp", FileType::OtherWritable),
+        Some(Style::new().bold().fg(Color::Blue))
+    );
+
+    let ls_colors = LsColors::parse("ln=target:*.tar=01;31");
+    assert!(ls_colors.symlink_as_target());
+    assert_eq!(
+        ls_colors.cell("x.tar", FileType::Symlink),
+        GridCell::from(String::from("x.tar")).with_style(Style::new().bold().fg(Color::Red))
+    );
+}
+
+#[test]
+fn test_gridcell_icon() {
+    // the width of the icon overrides the width of the glyph
+    let cells = [
+        GridCell::from(String::from("dir")).with_icon(Icon::new("\u{f115}").with_width(2)),
+        GridCell::from(String::from("file")).with_icon(Icon::new("📄")),
+        GridCell::from(String::from("plain")),
+        GridCell::from(String::from("x")).with_icon(Icon::new(">").with_gap(2)),
+    ];
+    assert_eq!(
+        cells.iter().map(GridCell::total_width).collect::<Vec<_>>(),
+        [6, 7, 5, 4]
+    );
+
+    let grid = Grid::new("|", Direction::TopToBottom, &cells);
+    let display = grid.fit_into_columns(2);
+    assert_eq!(display.column_widths(), &[7, 5]);
+    assert_eq!(
+        display.to_string(),
+        "\u{f115} dir |plain\n\
+         📄 file|>  x\n"
+    );
+
+    // contents are truncated after the icon
+    let grid = Grid::new("|", Direction::TopToBottom, &cells[1..2])
+        .with_overflow(Overflow::Truncate(Truncation::default()));
+    assert_eq!(grid.fit_into_width(6).unwrap().to_string(), "📄 f…\n");
+
+    // lines after the first line are indented by the icon
+    let cells = [
+        GridCell::from(String::from("a\nbc")).with_icon(Icon::new("*")),
+        GridCell::from(String::from("8, 1"))
+            .with_anchor(',')
+            .with_icon(Icon::new("#")),
+        GridCell::from(String::from("10, 20")).with_anchor(','),
+    ];
+    let grid = Grid::new("|", Direction::LeftToRight, &cells);
+    assert_eq!(
+        grid.fit_into_columns(1).to_string(),
+        "* a\n  bc\n# 8, 1\n 10, 20\n"
+    );
+
+    // widths and anchors saturate instead of overflowing
+    let cells = [GridCell {
+        alignment: Alignment::Anchored(usize::MAX),
+        ..GridCell::from(String::from("a")).with_icon(Icon::new("*"))
+    }];
+    let grid = Grid::new("|", Direction::LeftToRight, &cells);
+    assert_eq!(
+        grid.view().cell_alignment(&cells[0], 0),
+        Alignment::Anchored(usize::MAX)
+    );
+    assert_eq!(grid.fit_into_columns(1).to_string(), "* a\n");
+
+    let cell = GridCell::from(String::from("a")).with_icon(Icon::new("*").with_width(usize::MAX));
+    assert_eq!(cell.total_width(), usize::MAX);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_dir_options() {
+    use std::fs;
+
+    let dir = std::env::temp_dir().join(format!("nls_term_grid_test_dir_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir(&dir).unwrap();
+    fs::create_dir(dir.join("src")).unwrap();
+    fs::write(dir.join("b.txt"), "").unwrap();
+    fs::write(dir.join(".hidden"), "").unwrap();
+    fs::create_dir(dir.join("a_dir")).unwrap();
+
+    let names = |options: DirOptions| -> Vec<String> {
+        options
+            .read_dir(&dir)
+            .unwrap()
+            .into_iter()
+            .map(|cell| cell.contents)
+            .collect()
+    };
+
+    assert_eq!(names(DirOptions::new()), ["a_dir", "b.txt", "src"]);
+    assert_eq!(
+        names(
+            DirOptions::new()
+                .with_hidden_files(HiddenFiles::AlmostAll)
+                .with_indicator_style(IndicatorStyle::Slash)
+        ),
+        [".hidden", "a_dir/", "b.txt", "src/"]
+    );
+    assert_eq!(
+        names(
+            DirOptions::new()
+                .with_hidden_files(HiddenFiles::All)
+                .with_directories_first(true)
+        ),
+        [".", "..", "a_dir", "src", ".hidden", "b.txt"]
+    );
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+
+        fs::write(dir.join("run"), "").unwrap();
+        fs::set_permissions(dir.join("run"), fs::Permissions::from_mode(0o755)).unwrap();
+        std::os::unix::fs::symlink("src", dir.join("link")).unwrap();
+
+        let options = DirOptions::new()
+            .with_indicator_style(IndicatorStyle::Classify)
+            .with_directories_first(true);
+        assert_eq!(
+            names(options.clone()),
+            ["a_dir/", "link@", "src/", "b.txt", "run*"]
+        );
+        assert_eq!(
+            options
+                .cells_from_paths([dir.join("run"), dir.join("src")])
+                .iter()
+                .map(|cell| cell.contents.strip_prefix(dir.to_str().unwrap()).unwrap())
+                .collect::<Vec<_>>(),
+            ["/src/", "/run*"]
+        );
+    }
+
+    fs::remove_dir_all(&dir).unwrap();
+}
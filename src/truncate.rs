@@ -0,0 +1,260 @@
+use alloc::borrow::Cow;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use crate::ansi::{ansi_width, Segment, Segments};
+use crate::GridCell;
+
+/// Indicates what happens to GridCells too wide to fit into the display width
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Overflow {
+    /// [`Grid::fit_into_width`](struct.Grid.html#method.fit_into_width) returns `None`
+    Fail,
+    /// GridCells are truncated to be narrower than the display width
+    Truncate(Truncation),
+}
+
+impl Default for Overflow {
+    #[inline]
+    fn default() -> Self {
+        Self::Fail
+    }
+}
+
+/// Indicates how contents of a GridCell are truncated
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Truncation {
+    /// Where contents are removed
+    pub position: TruncationPosition,
+    /// The text written in place of the removed contents, `"…"` by default
+    pub ellipsis: Cow<'static, str>,
+}
+
+/// The default Truncation, used when GridCells are truncated without one
+pub(crate) static DEFAULT_TRUNCATION: Truncation = Truncation {
+    position: TruncationPosition::End,
+    ellipsis: Cow::Borrowed("…"),
+};
+
+impl Default for Truncation {
+    #[inline]
+    fn default() -> Self {
+        DEFAULT_TRUNCATION.clone()
+    }
+}
+
+/// Indicates where contents of a GridCell are removed when truncated
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TruncationPosition {
+    /// Removes contents from the end
+    ///
+    /// ## Example
+    ///
+    /// ```text
+    /// a_very_lon…
+    /// ```
+    End,
+    /// Removes contents from the middle, keeping the start and the end
+    ///
+    /// ## Example
+    ///
+    /// ```text
+    /// a_ver…_name
+    /// ```
+    Middle,
+}
+
+impl Default for TruncationPosition {
+    #[inline]
+    fn default() -> Self {
+        Self::End
+    }
+}
+
+impl GridCell<String> {
+    /// Truncates contents so that the total width, including the icon, is at most `max_width`
+    ///
+    /// Contents are only cut between grapheme clusters, so wide characters and
+    /// combining characters are never split. ANSI escape sequences are kept,
+    /// so colors are still reset after the removed contents. Each line of
+    /// contents with more than one line is truncated on its own. Returns
+    /// whether contents were truncated.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use nls_term_grid::{GridCell, Truncation, TruncationPosition};
+    ///
+    /// let mut cell = GridCell::from(String::from("a_very_long_file_name"));
+    /// let truncation = Truncation {
+    ///     position: TruncationPosition::Middle,
+    ///     ellipsis: "…".into(),
+    /// };
+    ///
+    /// assert!(cell.truncate(11, &truncation));
+    /// assert_eq!(cell.contents, "a_ver…_name");
+    /// assert_eq!(cell.width, 11);
+    /// ```
+    pub fn truncate(&mut self, max_width: usize, truncation: &Truncation) -> bool {
+        let max_width = max_width.saturating_sub(self.icon_width());
+        let truncated = if self.num_lines() > 1 {
+            truncate_lines(&self.contents, max_width, truncation)
+        } else {
+            truncate(&self.contents, max_width, truncation)
+        };
+
+        match truncated {
+            Some((contents, width)) => {
+                self.contents = contents;
+                self.width = width;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl<D: fmt::Display> GridCell<D> {
+    /// Returns a copy of the GridCell with contents truncated to `max_width`
+    pub(crate) fn truncated(&self, max_width: usize, truncation: &Truncation) -> GridCell<String> {
+        let mut cell = GridCell {
+            contents: self.contents.to_string(),
+            width: self.width,
+            height: self.height,
+            alignment: self.alignment,
+            hyperlink: self.hyperlink.clone(),
+            style: self.style,
+            icon: self.icon.clone(),
+        };
+        cell.truncate(max_width, truncation);
+
+        cell
+    }
+}
+
+enum Token<'a> {
+    Escape(&'a str),
+    Grapheme(&'a str, usize),
+}
+
+/// Returns every line of `s` truncated to `max_width` and the width of the
+/// widest line, or `None` if every line already fits
+fn truncate_lines(s: &str, max_width: usize, truncation: &Truncation) -> Option<(String, usize)> {
+    let mut truncated = String::with_capacity(s.len());
+    let mut width: usize = 0;
+    let mut is_truncated = false;
+
+    for (index, line) in s.split('\n').enumerate() {
+        if index != 0 {
+            truncated.push('\n');
+        }
+        match truncate(line, max_width, truncation) {
+            Some((line, line_width)) => {
+                truncated.push_str(&line);
+                width = width.max(line_width);
+                is_truncated = true;
+            }
+            None => {
+                truncated.push_str(line);
+                width = width.max(ansi_width(line));
+            }
+        }
+    }
+
+    is_truncated.then_some((truncated, width))
+}
+
+/// Returns `s` truncated to `max_width` and its width, or `None` if `s` already fits
+pub(crate) fn truncate(
+    s: &str,
+    max_width: usize,
+    truncation: &Truncation,
+) -> Option<(String, usize)> {
+    let mut tokens: Vec<Token<'_>> = Vec::new();
+    let mut width: usize = 0;
+    for segment in Segments::new(s) {
+        match segment {
+            Segment::Escape(escape) => tokens.push(Token::Escape(escape)),
+            Segment::Text(text) => {
+                for grapheme in text.graphemes(true) {
+                    let grapheme_width = UnicodeWidthStr::width(grapheme);
+                    width += grapheme_width;
+                    tokens.push(Token::Grapheme(grapheme, grapheme_width));
+                }
+            }
+        }
+    }
+
+    if width <= max_width {
+        return None;
+    }
+
+    // drop the ellipsis if even the ellipsis does not fit
+    let ellipsis_width = ansi_width(&truncation.ellipsis);
+    let (ellipsis, ellipsis_width) = if ellipsis_width <= max_width {
+        (&*truncation.ellipsis, ellipsis_width)
+    } else {
+        ("", 0)
+    };
+    let budget = max_width - ellipsis_width;
+    let start_budget = match truncation.position {
+        TruncationPosition::End => budget,
+        TruncationPosition::Middle => budget - budget / 2,
+    };
+
+    let mut keep: Vec<bool> = vec![true; tokens.len()];
+    let mut kept_width: usize = 0;
+
+    // keep graphemes from the start until start_budget is used up,
+    // the ellipsis is written in place of the first removed grapheme
+    let mut ellipsis_index = tokens.len();
+    for (index, token) in tokens.iter().enumerate() {
+        if let Token::Grapheme(_, grapheme_width) = token {
+            if kept_width + grapheme_width > start_budget {
+                ellipsis_index = index;
+                break;
+            }
+            kept_width += grapheme_width;
+        }
+    }
+
+    // keep graphemes from the end with the remaining budget
+    let mut end_budget = match truncation.position {
+        TruncationPosition::End => 0,
+        TruncationPosition::Middle => budget - kept_width,
+    };
+    let mut is_end_kept = true;
+    for (index, token) in tokens.iter().enumerate().skip(ellipsis_index).rev() {
+        if let Token::Grapheme(_, grapheme_width) = token {
+            if is_end_kept && *grapheme_width <= end_budget {
+                end_budget -= grapheme_width;
+                kept_width += grapheme_width;
+            } else {
+                is_end_kept = false;
+                keep[index] = false;
+            }
+        }
+    }
+
+    let mut truncated = String::with_capacity(s.len());
+    for (index, token) in tokens.iter().enumerate() {
+        if index == ellipsis_index {
+            truncated.push_str(ellipsis);
+        }
+        match token {
+            Token::Escape(escape) => truncated.push_str(escape),
+            Token::Grapheme(grapheme, _) if keep[index] => truncated.push_str(grapheme),
+            Token::Grapheme(..) => (),
+        }
+    }
+
+    Some((truncated, kept_width + ellipsis_width))
+}